@@ -0,0 +1,137 @@
+use std::fmt::Display;
+use num_bigint::{BigInt, BigUint};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+//what a part evaluates to, every integer type becomes one number so 5u8, 5i64 and "5" are equal,
+//multi-line text is a grid of rows, like letters drawn in pixels
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Number(BigInt),
+    Text(String),
+    Grid(Vec<String>),
+}
+
+impl Answer {
+    pub fn is_grid(&self) -> bool {
+        matches!(self, Answer::Grid(_))
+    }
+
+    pub fn as_number(&self) -> Option<&BigInt> {
+        match self {
+            Answer::Number(n) => Some(n),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{}", n),
+            Answer::Text(text) => write!(f, "{}", text),
+            Answer::Grid(rows) => write!(f, "{}", rows.join("\n")),
+        }
+    }
+}
+
+//text is read the way the site would: a number if it is one, a grid if it has several lines,
+//and text when a number would be written differently, like 0111, so it is always sent as given
+impl From<&str> for Answer {
+    fn from(text: &str) -> Answer {
+        let text = text.trim_end_matches('\n');
+        if text.contains('\n') {
+            return Answer::Grid(text.lines().map(|row| row.trim_end().to_string()).collect());
+        }
+        let text = text.trim();
+        match text.parse::<BigInt>() {
+            Ok(n) if n.to_string() == text => Answer::Number(n),
+            _ => Answer::Text(text.to_string()),
+        }
+    }
+}
+
+impl From<String> for Answer {
+    fn from(text: String) -> Answer {
+        Answer::from(text.as_str())
+    }
+}
+
+impl From<BigInt> for Answer {
+    fn from(n: BigInt) -> Answer {
+        Answer::Number(n)
+    }
+}
+
+impl From<BigUint> for Answer {
+    fn from(n: BigUint) -> Answer {
+        Answer::Number(BigInt::from(n))
+    }
+}
+
+macro_rules! from_integer {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(n: $t) -> Answer {
+                Answer::Number(BigInt::from(n))
+            }
+        })*
+    };
+}
+
+from_integer!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+//written as the text that would be submitted, so files and json read the same for every kind
+impl Serialize for Answer {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Answer {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Answer, D::Error> {
+        String::deserialize(deserializer).map(Answer::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_integers_are_equal_whatever_their_type() {
+        assert_eq!(Answer::from(5u8), Answer::from(5i64));
+        assert_eq!(Answer::from(-3isize), Answer::from("-3"));
+        assert_eq!(Answer::from(BigUint::from(7u32)), Answer::from(7usize));
+        assert_eq!(Answer::from(u128::MAX).to_string(), u128::MAX.to_string());
+        assert_ne!(Answer::from(5), Answer::from(6));
+    }
+
+    #[test]
+    fn test_text_and_grids() {
+        assert_eq!(Answer::from("NTWZZWHFV".to_string()), Answer::Text("NTWZZWHFV".to_string()));
+        let grid = Answer::from("#..#\n#..#\n");
+        assert_eq!(grid, Answer::Grid(vec!["#..#".to_string(), "#..#".to_string()]));
+        assert!(grid.is_grid());
+        assert_eq!(grid.to_string(), "#..#\n#..#");
+        assert_eq!(Answer::from(grid.to_string()), grid);
+    }
+
+    #[test]
+    fn test_numbers_are_kept_as_written() {
+        assert_eq!(Answer::from("0111"), Answer::Text("0111".to_string()));
+        assert_eq!(Answer::from("0111").to_string(), "0111");
+        assert_ne!(Answer::from("0111"), Answer::from("111"));
+        assert_eq!(Answer::from("+5"), Answer::Text("+5".to_string()));
+        assert_eq!(Answer::from(" 42\n"), Answer::from(42));
+        assert_eq!(Answer::from("0"), Answer::from(0));
+    }
+
+    #[test]
+    fn test_serde() {
+        let answers = vec![Answer::from(12u32), Answer::from("abc"), Answer::from("#.\n.#")];
+        let json = serde_json::to_string(&answers).unwrap();
+        assert_eq!(json, r##"["12","abc","#.\n.#"]"##);
+        let parsed: Vec<Answer> = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, answers);
+    }
+}
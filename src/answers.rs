@@ -0,0 +1,172 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use serde::{Deserialize, Serialize};
+use aoc::answer::Answer;
+use aoc::config::Config;
+use aoc::input::{Input, Source};
+use crate::error::AocError;
+use crate::run::{self, table, table_answer};
+
+//the answers the site accepted for one day
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Known {
+    pub year: u32,
+    pub day: u32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part1: Option<Answer>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part2: Option<Answer>,
+}
+
+impl Known {
+    pub fn part(&self, part: u32) -> Option<&Answer> {
+        match part {
+            1 => self.part1.as_ref(),
+            2 => self.part2.as_ref(),
+            _ => None,
+        }
+    }
+}
+
+//checked in, so a refactor can't quietly change an old day's answer
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct KnownAnswers {
+    #[serde(flatten)]
+    pub profiles: BTreeMap<String, Vec<Known>>,
+}
+
+impl KnownAnswers {
+    //a file that can't be read is an error, starting over would write over every recorded answer
+    pub fn load(config: &Config) -> Result<KnownAnswers, AocError> {
+        let path = &config.answers_file;
+        match fs::read_to_string(path) {
+            Ok(text) => toml::from_str(&text)
+                .map_err(|e| AocError::Io(format!("Could not read {}: {}", path.display(), e))),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(KnownAnswers::default()),
+            Err(e) => Err(AocError::io("read", path, e)),
+        }
+    }
+
+    pub fn save(&self, config: &Config) -> Result<(), AocError> {
+        let path = &config.answers_file;
+        fs::write(path, toml::to_string(self).unwrap()).map_err(|e| AocError::io("write", path, e))
+    }
+
+    //true when this is news, a day only has parts 1 and 2 so nothing else is ever recorded
+    pub fn set(&mut self, profile: &str, year: u32, day: u32, part: u32, answer: Answer) -> bool {
+        if part != 1 && part != 2 {
+            return false;
+        }
+        let days = self.profiles.entry(profile.to_string()).or_default();
+        let index = match days.iter().position(|k| k.year == year && k.day == day) {
+            Some(index) => index,
+            None => {
+                days.push(Known { year, day, part1: None, part2: None });
+                days.sort_by_key(|k| (k.year, k.day));
+                days.iter().position(|k| k.year == year && k.day == day).unwrap()
+            }
+        };
+        let known = if part == 1 { &mut days[index].part1 } else { &mut days[index].part2 };
+        if known.as_ref() == Some(&answer) {
+            return false;
+        }
+        *known = Some(answer);
+        true
+    }
+}
+
+//remember answers the site confirmed, for the configured profile
+pub fn record(config: &Config, year: u32, day: u32, answers: &[(u32, Answer)]) -> Result<(), AocError> {
+    let mut known = KnownAnswers::load(config)?;
+    let mut changed = false;
+    for (part, answer) in answers {
+        changed |= known.set(&config.profile, year, day, *part, answer.clone());
+    }
+    if changed {
+        known.save(config)?;
+        println!("Recorded the answers for {} day {} in {}", year, day, config.answers_file.display());
+    }
+    Ok(())
+}
+
+//rerun every day with known answers and report the ones that changed, an error if any did
+pub fn verify(config: &Config, year: Option<u32>, day: Option<u32>) -> Result<(), AocError> {
+    let known = KnownAnswers::load(config)?;
+    let days: Vec<&Known> = known.profiles.get(&config.profile).into_iter().flatten()
+        .filter(|k| year.is_none_or(|y| y == k.year) && day.is_none_or(|d| d == k.day))
+        .collect();
+    if days.is_empty() {
+        eprintln!("No known answers for profile {} in {}", config.profile, config.answers_file.display());
+        return Ok(());
+    }
+    let mut rows = vec![["year", "day", "part", "expected", "got", ""].map(String::from).to_vec()];
+    let mut mismatches = 0;
+    for known in days {
+        let answers = run::answers(config, &Input::new(known.year, known.day, Source::Config))
+            .inspect_err(|e| eprintln!("{}", e))
+            .ok();
+        for part in 1..=2 {
+            let expected = match known.part(part) {
+                Some(expected) => expected,
+                None => continue,
+            };
+            let got = answers.as_ref().and_then(|answers| answers.get(part as usize - 1));
+            let status = match got {
+                Some(got) if got == expected => "ok",
+                Some(_) => "MISMATCH",
+                None => "not run",
+            };
+            if status != "ok" {
+                mismatches += 1;
+            }
+            rows.push(vec![
+                known.year.to_string(),
+                known.day.to_string(),
+                part.to_string(),
+                table_answer(expected),
+                got.map(table_answer).unwrap_or_default(),
+                status.to_string(),
+            ]);
+        }
+    }
+    print!("{}", table(&rows));
+    println!("{} of {} answers differ", mismatches, rows.len() - 1);
+    match mismatches {
+        0 => Ok(()),
+        _ => Err(AocError::Mismatch(mismatches)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set() {
+        let mut known = KnownAnswers::default();
+        assert!(known.set("default", 2022, 10, 1, Answer::from(12460)));
+        assert!(known.set("default", 2022, 2, 1, Answer::from(10498)));
+        assert!(!known.set("default", 2022, 10, 1, Answer::from("12460")));
+        assert!(known.set("default", 2022, 10, 2, Answer::from("#.\n.#")));
+        let days: Vec<u32> = known.profiles["default"].iter().map(|k| k.day).collect();
+        assert_eq!(days, vec![2, 10]);
+        assert_eq!(known.profiles["default"][1].part(2), Some(&Answer::from("#.\n.#")));
+        assert!(!known.profiles.contains_key("work"));
+        assert_eq!(known.profiles["default"][1].part(3), None);
+        assert!(!known.set("default", 2022, 11, 3, Answer::from(1)));
+        assert!(!known.set("default", 2022, 10, 0, Answer::from(1)));
+        assert_eq!(known.profiles["default"].len(), 2);
+        assert_eq!(known.profiles["default"][1].part1, Some(Answer::from(12460)));
+    }
+
+    #[test]
+    fn test_file_format() {
+        let mut known = KnownAnswers::default();
+        known.set("default", 2022, 1, 1, Answer::from(67450));
+        let text = toml::to_string(&known).unwrap();
+        assert_eq!(text, "[[default]]\nyear = 2022\nday = 1\npart1 = \"67450\"\n");
+        let parsed: KnownAnswers = toml::from_str(&text).unwrap();
+        assert_eq!(parsed.profiles, known.profiles);
+    }
+}
@@ -0,0 +1,267 @@
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+use criterion::{BenchmarkId, Criterion};
+use serde::Deserialize;
+use aoc::config::Config;
+use aoc::input::{Input, Source};
+use aoc::solution::{self, DynSolution};
+use crate::error::AocError;
+use crate::run::table;
+
+//criterion's own default, so plain runs compare with the previous plain run
+pub const DEFAULT_BASELINE: &str = "base";
+//relative changes smaller than this are noise, for criterion's report and ours
+const NOISE: f64 = 0.02;
+
+//what the new measurements are checked against
+pub enum Baseline {
+    //compare with the last run saved under this name, then replace it
+    Save(String),
+    //compare with this saved run and leave it alone
+    Compare(String),
+}
+
+impl Baseline {
+    fn name(&self) -> &str {
+        match self {
+            Baseline::Save(name) | Baseline::Compare(name) => name,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+struct Interval {
+    lower_bound: f64,
+    upper_bound: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+struct Estimate {
+    point_estimate: f64,
+    confidence_interval: Interval,
+}
+
+//the part of criterion's estimates.json we report on, times in nanoseconds
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+struct Estimates {
+    mean: Estimate,
+}
+
+fn read_estimates(path: &Path) -> Option<Estimates> {
+    serde_json::from_str(&fs::read_to_string(path).ok()?).ok()
+}
+
+//slower only counts when the intervals don't overlap and the change is more than noise
+fn verdict(before: &Estimates, now: &Estimates) -> &'static str {
+    let change = now.mean.point_estimate / before.mean.point_estimate - 1.0;
+    if change > NOISE && now.mean.confidence_interval.lower_bound > before.mean.confidence_interval.upper_bound {
+        "regressed"
+    } else if change < -NOISE && now.mean.confidence_interval.upper_bound < before.mean.confidence_interval.lower_bound {
+        "improved"
+    } else {
+        "no change"
+    }
+}
+
+fn format_nanos(nanos: f64) -> String {
+    match nanos {
+        n if n < 1e3 => format!("{:.1}ns", n),
+        n if n < 1e6 => format!("{:.1}µs", n / 1e3),
+        n if n < 1e9 => format!("{:.1}ms", n / 1e6),
+        n => format!("{:.2}s", n / 1e9),
+    }
+}
+
+fn format_change(before: &Estimates, now: &Estimates) -> String {
+    format!("{:+.1}%", (now.mean.point_estimate / before.mean.point_estimate - 1.0) * 100.0)
+}
+
+//what one benchmark times
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Target {
+    Parse,
+    Part(u32),
+    Alternative(u32, &'static str),
+}
+
+impl Target {
+    //also the benchmark's directory under the day's group in criterion's output
+    fn name(&self) -> String {
+        match self {
+            Target::Parse => "parse".to_string(),
+            Target::Part(part) => format!("part{}", part),
+            Target::Alternative(part, name) => format!("part{}/{}", part, name),
+        }
+    }
+}
+
+//parse first when the day parses, then each part followed by its alternatives so they sit side by side
+fn targets(solution: &dyn DynSolution, part: Option<u32>) -> Vec<Target> {
+    let alternatives = solution.alternatives();
+    let mut targets = Vec::new();
+    if part.is_none() && solution.parses() {
+        targets.push(Target::Parse);
+    }
+    for p in (1..=solution.parts()).filter(|p| part.is_none_or(|part| part == *p)) {
+        targets.push(Target::Part(p));
+        targets.extend(alternatives.iter().filter(|(a, _)| *a == p).map(|(_, name)| Target::Alternative(p, name)));
+    }
+    targets
+}
+
+fn criterion(config: &Config, baseline: &Baseline, quick: bool) -> Criterion {
+    let criterion = Criterion::default()
+        .output_directory(&config.bench_dir)
+        .noise_threshold(NOISE);
+    let criterion = match baseline {
+        Baseline::Save(name) => criterion.save_baseline(name.clone()),
+        Baseline::Compare(name) => criterion.retain_baseline(name.clone()),
+    };
+    //enough to see a big change, not to trust a small one
+    if quick {
+        criterion.sample_size(10)
+            .warm_up_time(Duration::from_millis(100))
+            .measurement_time(Duration::from_millis(500))
+            .nresamples(10_000)
+            .without_plots()
+    } else {
+        criterion
+    }
+}
+
+//time parsing and each part on the real input, then compare with the baseline's times
+pub fn bench(config: &Config, year: u32, day: u32, part: Option<u32>, baseline: &Baseline, quick: bool) -> Result<(), AocError> {
+    let solution = solution::find(year, day).ok_or_else(|| AocError::NoSolution(format!("{} day {}", year, day)))?;
+    if let Some(part) = part {
+        if part == 0 || part > solution.parts() {
+            return Err(AocError::NoSolution(format!("{} day {} part {}", year, day, part)));
+        }
+    }
+    let input = Input::new(year, day, Source::Config);
+    let text = input.read(config)
+        .map_err(|e| AocError::Input(format!("Could not read input {}: {}", input.describe(config), e)))?;
+    if cfg!(debug_assertions) {
+        eprintln!("aoc was built without optimizations, run it with `cargo run --release -- bench` for real times");
+    }
+    let group = format!("{}_{}", year, day);
+    let targets = targets(solution, part);
+    let dir = |target: &Target, run: &str| config.bench_dir.join(&group).join(target.name()).join(run).join("estimates.json");
+    let before: Vec<Option<Estimates>> = targets.iter().map(|target| read_estimates(&dir(target, baseline.name()))).collect();
+    //criterion exits the whole process when asked to compare with a run it doesn't have
+    if let Baseline::Compare(name) = baseline {
+        if before.iter().any(|b| b.is_none()) {
+            return Err(AocError::Bench(format!("No baseline {} for {} day {}, save one with --save-baseline {}", name, year, day, name)));
+        }
+    }
+
+    let mut criterion = criterion(config, baseline, quick);
+    let parsed = solution.parse(&text);
+    {
+        let mut benches = criterion.benchmark_group(&group);
+        for target in &targets {
+            let parsed = parsed.as_ref();
+            match *target {
+                Target::Parse => {
+                    benches.bench_function("parse", |b| b.iter(|| solution.parse(&text)));
+                }
+                Target::Part(part) => {
+                    benches.bench_function(target.name(), |b| b.iter(|| solution.part(part, parsed)));
+                }
+                //looked up before timing, so only the alternative itself is measured
+                Target::Alternative(part, name) => if let Some(solve) = solution.alternative_solver(part, name) {
+                    benches.bench_function(BenchmarkId::new(format!("part{}", part), name), |b| b.iter(|| solve(parsed)));
+                }
+            }
+        }
+        benches.finish();
+    }
+    criterion.final_summary();
+
+    let mut rows = vec![["", "now", "vs part", baseline.name(), "change", ""].map(String::from).to_vec()];
+    let now: Vec<Option<Estimates>> = targets.iter().map(|target| read_estimates(&dir(target, "new"))).collect();
+    for (i, target) in targets.iter().enumerate() {
+        let now_estimates = match now[i] {
+            Some(now) => now,
+            None => continue,
+        };
+        //alternatives are timed relative to the part they stand in for
+        let relative = match target {
+            Target::Alternative(part, _) => targets.iter()
+                .position(|t| *t == Target::Part(*part))
+                .and_then(|p| now[p])
+                .map(|part| format_change(&part, &now_estimates))
+                .unwrap_or_default(),
+            _ => String::new(),
+        };
+        let mut row = vec![target.name(), format_nanos(now_estimates.mean.point_estimate), relative];
+        match before[i] {
+            Some(before) => row.extend([
+                format_nanos(before.mean.point_estimate),
+                format_change(&before, &now_estimates),
+                verdict(&before, &now_estimates).to_string(),
+            ]),
+            None => row.push("-".to_string()),
+        }
+        rows.push(row);
+    }
+    println!();
+    print!("{}", table(&rows));
+    if let Baseline::Save(name) = baseline {
+        println!("Saved as baseline {} in {}", name, config.bench_dir.join(&group).display());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn estimates(point: f64, lower: f64, upper: f64) -> Estimates {
+        Estimates { mean: Estimate { point_estimate: point, confidence_interval: Interval { lower_bound: lower, upper_bound: upper } } }
+    }
+
+    #[test]
+    fn test_verdict() {
+        let before = estimates(100.0, 98.0, 102.0);
+        assert_eq!(verdict(&before, &estimates(120.0, 118.0, 122.0)), "regressed");
+        assert_eq!(verdict(&before, &estimates(80.0, 78.0, 82.0)), "improved");
+        assert_eq!(verdict(&before, &estimates(101.0, 99.0, 103.0)), "no change");
+        //too noisy to tell
+        assert_eq!(verdict(&before, &estimates(120.0, 90.0, 150.0)), "no change");
+        assert_eq!(format_change(&before, &estimates(120.0, 118.0, 122.0)), "+20.0%");
+    }
+
+    #[test]
+    fn test_read_estimates() {
+        let json = r#"{"mean":{"confidence_interval":{"confidence_level":0.95,"lower_bound":1.5,"upper_bound":2.5},"point_estimate":2.0,"standard_error":0.1},"median":{}}"#;
+        let dir = std::env::temp_dir().join(format!("aoc-bench-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("estimates.json"), json).unwrap();
+        assert_eq!(read_estimates(&dir.join("estimates.json")), Some(estimates(2.0, 1.5, 2.5)));
+        assert_eq!(read_estimates(&dir.join("missing.json")), None);
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_format_nanos() {
+        assert_eq!(format_nanos(512.0), "512.0ns");
+        assert_eq!(format_nanos(2_500.0), "2.5µs");
+        assert_eq!(format_nanos(3_300_000.0), "3.3ms");
+        assert_eq!(format_nanos(4_000_000_000.0), "4.00s");
+    }
+
+    #[test]
+    fn test_targets() {
+        let names = |solution, part| targets(solution, part).iter().map(Target::name).collect::<Vec<String>>();
+        let day1 = solution::find(2022, 1).unwrap();
+        //day 1 keeps the raw text, so there is no parsing to time
+        assert_eq!(names(day1, None), vec![
+            "part1", "part1/functional", "part1/parallel", "part2", "part2/functional", "part2/parallel",
+        ]);
+        assert_eq!(names(day1, Some(2)), vec!["part2", "part2/functional", "part2/parallel"]);
+        assert_eq!(names(solution::find(2022, 4).unwrap(), None), vec!["parse", "part1", "part2"]);
+        //day 2 only solves part 1
+        assert_eq!(names(solution::find(2022, 2).unwrap(), None), vec!["part1"]);
+    }
+}
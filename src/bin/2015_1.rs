@@ -1,27 +1,11 @@
-use std::env;
-use std::fs::File;
-use std::io::{BufReader, BufRead};
-
+use aoc::input;
+use aoc::y2015::day1::{part1, part2};
 
 fn main() {
-    let input_file = env::args().nth(1).unwrap();
-    //open file
-    let file = File::open(input_file).unwrap();
-    let mut reader = BufReader::new(file);
-    let mut line = String::new();
-    reader.read_line(&mut line).unwrap();
-
-    let mut count = 0;
-    for (i, char) in line.chars().enumerate() {
-        match char {
-            '(' => count += 1,
-            ')' => count -= 1,
-            _ => {},
-        }
-        if count == -1 {
-            println!("Entered basement at position {}", i + 1);
-            return;
-        }
+    let input = input::load(2015, 1);
+    println!("{}", part1(&input));
+    match part2(&input) {
+        Some(position) => println!("Entered basement at position {}", position),
+        None => println!("Never entered the basement"),
     }
-    println!("{}", count);
 }
@@ -1,20 +1,8 @@
-use itertools::Itertools;
-
-fn all_unique(s: &[u8]) -> bool {
-    s.into_iter().unique().count() == s.len()
-}
-
-fn marker_position(s: &[u8], n: &usize) -> usize {
-    for i in 0..s.len()-(n-1) {
-        if all_unique(&s[i..i+n]) {
-            return i+n;
-        }
-    }
-    panic!("Could not find marker");
-}
+use aoc::input;
+use aoc::y2022::day6::{part1, part2};
 
 fn main () {
-    let signal: &[u8] = include_str!("../../inputs/2022_6.txt").as_bytes();
-    println!("part1: {}", marker_position(signal, &4));
-    println!("part2: {}", marker_position(signal, &14));
+    let input = &input::load(2022, 6);
+    println!("part1: {}", part1(input));
+    println!("part2: {}", part2(input));
 }
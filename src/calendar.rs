@@ -0,0 +1,130 @@
+use regex::Regex;
+use aoc::config::Config;
+use aoc::solution;
+use crate::client::{Cache, Client};
+use crate::error::AocError;
+use crate::profile::session_cookie;
+use crate::unlock;
+
+//stars earned on each day, as the calendar's classes show them
+pub fn stars(page: &str) -> [u32; 25] {
+    let day = Regex::new(r#"class="calendar-day(\d+)(?: calendar-(complete|verycomplete))?""#).unwrap();
+    let mut stars = [0; 25];
+    for c in day.captures_iter(page) {
+        let n: usize = c[1].parse().unwrap();
+        if (1..=25).contains(&n) {
+            stars[n - 1] = match c.get(2).map(|m| m.as_str()) {
+                Some("verycomplete") => 2,
+                Some(_) => 1,
+                None => 0,
+            };
+        }
+    }
+    stars
+}
+
+//where the site and the registered solutions disagree
+pub fn mismatches(year: u32, stars: &[u32; 25], parts: &[u32; 25]) -> Vec<String> {
+    let mut found = Vec::new();
+    for day in 1..=25 {
+        let (stars, parts) = (stars[day as usize - 1], parts[day as usize - 1]);
+        //the last day's second star comes free with the other 49
+        let needed = if day == 25 { stars.min(1) } else { stars };
+        if stars > 0 && parts == 0 {
+            found.push(format!("{} day {}: {} on the site but no solution", year, day, plural(stars)));
+        } else if parts > 0 && stars == 0 {
+            found.push(format!("{} day {}: solution but no stars on the site", year, day));
+        } else if parts < needed {
+            found.push(format!("{} day {}: {} on the site but only part {} is solved", year, day, plural(stars), parts));
+        }
+    }
+    found
+}
+
+fn plural(stars: u32) -> String {
+    if stars == 1 { "1 star".to_string() } else { format!("{} stars", stars) }
+}
+
+//one column per day, like `aoc list`: * for both stars, + for one, and the parts solved here
+pub fn render(year: u32, stars: &[u32; 25], parts: &[u32; 25]) -> String {
+    let mut s = format!("{:<6}", year);
+    for day in 1..=25 {
+        s.push_str(&format!("{:>3}", day));
+    }
+    s.push_str(&format!("\n{:<6}", "stars"));
+    for day_stars in stars {
+        s.push_str(&format!("{:>3}", ['.', '+', '*'][*day_stars as usize]));
+    }
+    s.push_str(&format!("\n{:<6}", "parts"));
+    for day_parts in parts {
+        let cell = if *day_parts == 0 { '.' } else { char::from_digit(*day_parts, 10).unwrap() };
+        s.push_str(&format!("{:>3}", cell));
+    }
+    s.push('\n');
+    s
+}
+
+pub async fn calendar(config: &Config, year: Option<u32>) -> Result<(), AocError> {
+    let year = year.or(config.year).unwrap_or_else(|| unlock::latest_event(chrono::Utc::now()));
+    let client = Client::new(&config.root, session_cookie(&config.profile)?)?;
+    //stars change as days are solved, so always ask
+    let page = client.get(&format!("/{}", year), Cache::Never).await?;
+    let stars = stars(&page);
+    let mut parts = [0; 25];
+    for (day, day_parts) in parts.iter_mut().enumerate() {
+        *day_parts = solution::find(year, day as u32 + 1).map(|s| s.parts()).unwrap_or(0);
+    }
+    print!("{}", render(year, &stars, &parts));
+    println!("Total: {}", plural(stars.iter().sum()));
+    for mismatch in mismatches(year, &stars, &parts) {
+        println!("{}", mismatch);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_input() -> &'static str {
+r#"<pre class="calendar"><a aria-label="Day 1, two stars" href="/2022/day/1" class="calendar-day1 calendar-verycomplete"><span class="calendar-day"> 1</span></a>
+<a aria-label="Day 2, one star" href="/2022/day/2" class="calendar-day2 calendar-complete"><span class="calendar-day"> 2</span></a>
+<a aria-label="Day 3" href="/2022/day/3" class="calendar-day3"><span class="calendar-day"> 3</span></a>
+<span aria-hidden="true" class="calendar-day4"><span class="calendar-day"> 4</span></span>
+</pre>"#
+    }
+
+    #[test]
+    fn test_stars() {
+        let stars = stars(test_input());
+        assert_eq!(stars[..5], [2, 1, 0, 0, 0]);
+    }
+
+    #[test]
+    fn test_mismatches() {
+        let mut stars = [0; 25];
+        let mut parts = [0; 25];
+        stars[..4].copy_from_slice(&[2, 2, 1, 0]);
+        parts[..4].copy_from_slice(&[2, 1, 0, 2]);
+        stars[24] = 2;
+        parts[24] = 1;
+        assert_eq!(mismatches(2022, &stars, &parts), vec![
+            "2022 day 2: 2 stars on the site but only part 1 is solved",
+            "2022 day 3: 1 star on the site but no solution",
+            "2022 day 4: solution but no stars on the site",
+        ]);
+    }
+
+    #[test]
+    fn test_render() {
+        let mut stars = [0; 25];
+        let mut parts = [0; 25];
+        stars[..2].copy_from_slice(&[2, 1]);
+        parts[..3].copy_from_slice(&[2, 1, 2]);
+        let output = render(2022, &stars, &parts);
+        let lines: Vec<&str> = output.lines().collect();
+        assert!(lines[0].starts_with("2022    1  2  3  4"));
+        assert!(lines[1].starts_with("stars   *  +  .  ."));
+        assert!(lines[2].starts_with("parts   2  1  2  ."));
+    }
+}
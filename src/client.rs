@@ -0,0 +1,187 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use sha2::{Digest, Sha256};
+use tokio::sync::Mutex;
+use crate::error::AocError;
+
+//how long a fetched page can be reused
+pub enum Cache {
+    //content that never changes once it exists, like inputs
+    Forever,
+    //pages that only stop changing once they are complete, like a puzzle before part 2 unlocks
+    WhenComplete(fn(&str) -> bool),
+    //pages the site asks not to fetch more often than this, like leaderboards, as long as they are usable
+    For(Duration, fn(&str) -> bool),
+    //pages that must come from the site, like checking a login
+    Never,
+}
+
+//the one client every command talks to the site through: identifies itself, spaces out requests
+//(also across runs) and keeps fetched pages on disk so they are only ever downloaded once
+pub struct Client {
+    http: reqwest::Client,
+    pub base_url: String,
+    //fetch from the site even when a cached page exists
+    pub refresh: bool,
+    min_gap: Duration,
+    state_dir: PathBuf,
+    cache_dir: PathBuf,
+    throttle: Mutex<()>,
+}
+
+//the site to talk to, overridden with AOC_BASE_URL to point at a local server
+pub fn base_url() -> String {
+    env::var("AOC_BASE_URL")
+        .map(|url| url.trim_end_matches('/').to_string())
+        .unwrap_or_else(|_| "https://adventofcode.com".to_string())
+}
+
+//the site asks automated tools to say who is behind them
+pub fn user_agent() -> String {
+    let name = format!("{}/{}", env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"));
+    match env::var("AOC_CONTACT") {
+        Ok(contact) if !contact.trim().is_empty() => format!("{} (by {})", name, contact.trim()),
+        _ => name,
+    }
+}
+
+fn min_gap() -> Duration {
+    let seconds = env::var("AOC_MIN_GAP_SECS").ok().and_then(|s| s.parse::<f64>().ok()).unwrap_or(3.0);
+    Duration::from_secs_f64(seconds)
+}
+
+pub fn get_request_headers(cookie: String) -> Result<reqwest::header::HeaderMap, AocError> {
+    let mut headers = reqwest::header::HeaderMap::new();
+    //a cookie pasted with stray characters can't even be sent
+    let value = reqwest::header::HeaderValue::from_str(format!{"session={}", cookie}.as_str())
+        .map_err(|_| AocError::InvalidCookie)?;
+    headers.insert(reqwest::header::COOKIE, value);
+    Ok(headers)
+}
+
+//error pages come back with a non-success status, and must not be saved as if they were the content
+async fn check_response(resp: reqwest::Response) -> Result<String, AocError> {
+    let status = resp.status();
+    let body = resp.text().await?;
+    if status.is_success() {
+        Ok(body)
+    } else {
+        Err(AocError::from_status(status.as_u16(), &body))
+    }
+}
+
+//pages differ between accounts and between sites, so each session on each site gets its own cache,
+//named the same by every build
+fn cache_key(base_url: &str, cookie: &str) -> String {
+    let digest = Sha256::new().chain_update(base_url).chain_update("\n").chain_update(cookie).finalize();
+    digest.iter().take(8).map(|b| format!("{:02x}", b)).collect()
+}
+
+fn now() -> Duration {
+    SystemTime::now().duration_since(UNIX_EPOCH).unwrap()
+}
+
+impl Client {
+    //state and cache are kept under the project root, wherever in the project the command runs
+    pub fn new(root: &Path, cookie: String) -> Result<Client, AocError> {
+        let http = reqwest::Client::builder()
+            .user_agent(user_agent())
+            .default_headers(get_request_headers(cookie.clone())?)
+            .build()?;
+        let base_url = base_url();
+        let state_dir = root.join(".aoc");
+        let cache_dir = state_dir.join("cache").join(cache_key(&base_url, &cookie));
+        Ok(Client { http, base_url, refresh: false, min_gap: min_gap(), state_dir, cache_dir, throttle: Mutex::new(()) })
+    }
+
+    fn cache_path(&self, path: &str) -> PathBuf {
+        self.cache_dir.join(path.trim_start_matches('/').replace('/', "_"))
+    }
+
+    fn cached(&self, path: &str, cache: &Cache) -> Option<String> {
+        let cache_path = self.cache_path(path);
+        let body = fs::read_to_string(&cache_path).ok()?;
+        let fresh = match cache {
+            Cache::Forever => true,
+            Cache::WhenComplete(complete) => complete(&body),
+            Cache::For(duration, usable) => usable(&body) && fs::metadata(&cache_path).ok()
+                .and_then(|m| m.modified().ok())
+                .and_then(|modified| modified.elapsed().ok())
+                .is_some_and(|age| age < *duration),
+            Cache::Never => false,
+        };
+        if fresh { Some(body) } else { None }
+    }
+
+    fn store(&self, path: &str, body: &str, cache: &Cache) -> Result<(), AocError> {
+        match cache {
+            Cache::WhenComplete(complete) if !complete(body) => return Ok(()),
+            Cache::For(_, usable) if !usable(body) => return Ok(()),
+            Cache::Never => return Ok(()),
+            _ => {}
+        }
+        fs::create_dir_all(&self.cache_dir).map_err(|e| AocError::io("create", &self.cache_dir, e))?;
+        let cache_path = self.cache_path(path);
+        fs::write(&cache_path, body).map_err(|e| AocError::io("write", &cache_path, e))
+    }
+
+    //wait out the gap since the last request, whichever run made it
+    async fn wait_turn(&self) -> Result<(), AocError> {
+        let _turn = self.throttle.lock().await;
+        let last_request_path = self.state_dir.join("last_request");
+        let last = fs::read_to_string(&last_request_path).ok()
+            .and_then(|s| s.trim().parse::<u64>().ok())
+            .map(Duration::from_millis)
+            .unwrap_or_default();
+        let wait = (last + self.min_gap).saturating_sub(now());
+        if !wait.is_zero() {
+            tokio::time::sleep(wait).await;
+        }
+        fs::create_dir_all(&self.state_dir).map_err(|e| AocError::io("create", &self.state_dir, e))?;
+        fs::write(&last_request_path, now().as_millis().to_string())
+            .map_err(|e| AocError::io("write", &last_request_path, e))
+    }
+
+    pub async fn get(&self, path: &str, cache: Cache) -> Result<String, AocError> {
+        if !self.refresh {
+            if let Some(body) = self.cached(path, &cache) {
+                return Ok(body);
+            }
+        }
+        self.wait_turn().await?;
+        let resp = self.http.get(format!("{}{}", self.base_url, path)).send().await?;
+        let body = check_response(resp).await?;
+        self.store(path, &body, &cache)?;
+        Ok(body)
+    }
+
+    pub async fn post(&self, path: &str, form: &[(&str, String)]) -> Result<String, AocError> {
+        self.wait_turn().await?;
+        let resp = self.http.post(format!("{}{}", self.base_url, path)).form(form).send().await?;
+        check_response(resp).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cache_path() {
+        let client = Client::new(Path::new("../.."), "cookie".to_string()).unwrap();
+        let path = client.cache_path("/2022/day/1/input");
+        assert_eq!(path, Path::new("../../.aoc/cache").join(cache_key(&base_url(), "cookie")).join("2022_day_1_input"));
+        let other = Client::new(Path::new("../.."), "other".to_string()).unwrap();
+        assert_ne!(path, other.cache_path("/2022/day/1/input"));
+    }
+
+    #[test]
+    fn test_cache_key() {
+        //the first bytes of a sha256, so the same session finds the same cache after a rebuild
+        assert_eq!(cache_key("https://adventofcode.com", "cookie"), "ea9c3a03796e8e37");
+        assert_ne!(cache_key("https://adventofcode.com", "cookie"), cache_key("http://127.0.0.1:8080", "cookie"));
+        assert_ne!(cache_key("https://adventofcode.com", "cookie"), cache_key("https://adventofcode.com", "other"));
+    }
+}
@@ -0,0 +1,137 @@
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use serde::Deserialize;
+
+pub const CONFIG_FILE: &str = "aoc.toml";
+pub const DEFAULT_PROFILE: &str = "default";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, clap::ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum Format {
+    Text,
+    Json,
+}
+
+//project settings from aoc.toml, the command line overrides any of them
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    //the year commands use when only given a day
+    pub year: Option<u32>,
+    pub input_dir: PathBuf,
+    pub puzzle_dir: PathBuf,
+    //the answers each profile's solutions are known to give, see `aoc verify`
+    pub answers_file: PathBuf,
+    //where `aoc bench` keeps criterion's measurements and baselines
+    pub bench_dir: PathBuf,
+    pub profile: String,
+    pub format: Format,
+    //the directory holding aoc.toml, the project's sources are under it
+    #[serde(skip)]
+    pub root: PathBuf,
+}
+
+impl Default for Config {
+    fn default() -> Config {
+        Config {
+            year: None,
+            input_dir: PathBuf::from("inputs"),
+            puzzle_dir: PathBuf::from("puzzles"),
+            answers_file: PathBuf::from("answers.toml"),
+            bench_dir: PathBuf::from("target/criterion"),
+            profile: DEFAULT_PROFILE.to_string(),
+            format: Format::Text,
+            root: PathBuf::new(),
+        }
+    }
+}
+
+//the nearest directory at or above the current one holding aoc.toml, as a relative path
+fn find_root() -> Option<PathBuf> {
+    let mut root = PathBuf::new();
+    let mut dir = std::env::current_dir().ok()?;
+    loop {
+        if dir.join(CONFIG_FILE).exists() {
+            return Some(root);
+        }
+        if !dir.pop() {
+            return None;
+        }
+        root.push("..");
+    }
+}
+
+impl Config {
+    pub fn parse(text: &str) -> Result<Config, toml::de::Error> {
+        toml::from_str(text)
+    }
+
+    //relative directories are relative to aoc.toml, so commands and binaries work from anywhere in the project,
+    //an aoc.toml that can't be read is an error with the reason rather than the defaults
+    pub fn load() -> Result<Config, String> {
+        let root = match find_root() {
+            Some(root) => root,
+            None => return Ok(Config::default()),
+        };
+        let path = root.join(CONFIG_FILE);
+        let mut config = fs::read_to_string(&path)
+            .map_err(|e| e.to_string())
+            .and_then(|text| Config::parse(&text).map_err(|e| e.to_string()))
+            .map_err(|e| format!("Could not read {}: {}", path.display(), e))?;
+        config.input_dir = root.join(&config.input_dir);
+        config.puzzle_dir = root.join(&config.puzzle_dir);
+        config.answers_file = root.join(&config.answers_file);
+        config.bench_dir = root.join(&config.bench_dir);
+        config.root = root;
+        Ok(config)
+    }
+
+    //the default profile keeps the original layout, other profiles get a folder each
+    pub fn input_path(&self, year: u32, day: u32) -> PathBuf {
+        let file = format!("{}_{}.txt", year, day);
+        if self.profile == DEFAULT_PROFILE {
+            self.input_dir.join(file)
+        } else {
+            self.input_dir.join(&self.profile).join(file)
+        }
+    }
+
+    pub fn puzzle_path(&self, year: u32, day: u32) -> PathBuf {
+        self.puzzle_dir.join(format!("{}_{}.md", year, day))
+    }
+
+    pub fn read_input(&self, year: u32, day: u32) -> io::Result<String> {
+        fs::read_to_string(self.input_path(year, day))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let config = Config::parse("year = 2022\ninput_dir = \"data/inputs\"\nformat = \"json\"\n").unwrap();
+        assert_eq!(config.year, Some(2022));
+        assert_eq!(config.input_dir, PathBuf::from("data/inputs"));
+        assert_eq!(config.puzzle_dir, PathBuf::from("puzzles"));
+        assert_eq!(config.profile, DEFAULT_PROFILE);
+        assert_eq!(config.format, Format::Json);
+        assert!(Config::parse("inputs = \"x\"\n").is_err());
+    }
+
+    #[test]
+    fn test_input_path() {
+        let mut config = Config::default();
+        assert_eq!(config.input_path(2022, 1), PathBuf::from("inputs/2022_1.txt"));
+        config.profile = "work".to_string();
+        assert_eq!(config.input_path(2022, 1), PathBuf::from("inputs/work/2022_1.txt"));
+    }
+
+    #[test]
+    fn test_load_finds_the_project_file() {
+        let config = Config::load().unwrap();
+        assert!(config.read_input(2022, 1).is_ok());
+    }
+}
@@ -0,0 +1,141 @@
+use std::fmt::Display;
+use std::io;
+use std::path::Path;
+
+//everything that can go wrong talking to the site or with the project's files
+#[derive(Debug, PartialEq, Eq)]
+pub enum AocError {
+    MissingCookie,
+    InvalidCookie,
+    NotYetAvailable,
+    ServerError(u16),
+    Network(String),
+    //a project file could not be read or written
+    Io(String),
+    //a day was given without a year and aoc.toml has no default
+    NoYear,
+    //what has no solution, like "2022 day 5" or "2022 day 5 part 2"
+    NoSolution(String),
+    //the day's input could not be read, with the reason
+    Input(String),
+    //the site sent something other than the leaderboard, with the reason
+    Leaderboard(String),
+    //aoc.toml could not be read, with the reason
+    Config(String),
+    //what the site has no puzzle for, like "2014 day 3" or "2022 day 26"
+    NoPuzzle(String),
+    //how many known answers a solution no longer gives
+    Mismatch(usize),
+    //the benchmark could not be run, with the reason
+    Bench(String),
+    //why an answer was not sent to the site
+    Refused(String),
+    //the site's verdict on an answer it didn't accept
+    NotAccepted(String),
+}
+
+impl Display for AocError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocError::MissingCookie => write!(f, "No session cookie, save one with `aoc login` or set AOC_COOKIE"),
+            AocError::InvalidCookie => write!(f, "The session cookie was not accepted, it has probably expired. Log in on the site again and run `aoc login`"),
+            AocError::NotYetAvailable => write!(f, "This puzzle is not available yet"),
+            AocError::ServerError(status) => write!(f, "The server answered with an error ({}), try again later", status),
+            AocError::Network(e) => write!(f, "Could not reach the server: {}", e),
+            AocError::Io(e) => write!(f, "{}", e),
+            AocError::NoYear => write!(f, "No year given, pass one or set a default year in aoc.toml"),
+            AocError::NoSolution(what) => write!(f, "No solution for {}", what),
+            AocError::Input(e) => write!(f, "{}", e),
+            AocError::Leaderboard(e) => write!(f, "{}", e),
+            AocError::Config(e) => write!(f, "{}", e),
+            AocError::NoPuzzle(what) => write!(f, "There is no puzzle for {}", what),
+            AocError::Mismatch(count) => write!(f, "{} of the known answers differ", count),
+            AocError::Bench(e) => write!(f, "{}", e),
+            AocError::Refused(reason) => write!(f, "Not submitting: {}", reason),
+            AocError::NotAccepted(verdict) => write!(f, "{}", verdict),
+        }
+    }
+}
+
+impl From<reqwest::Error> for AocError {
+    fn from(e: reqwest::Error) -> Self {
+        AocError::Network(e.to_string())
+    }
+}
+
+impl AocError {
+    //non-success responses: 404 before a day unlocks, 400 when the site doesn't know the session
+    pub fn from_status(status: u16, body: &str) -> AocError {
+        match status {
+            404 => AocError::NotYetAvailable,
+            400 | 401 | 403 => AocError::InvalidCookie,
+            _ if body.contains("Please log in") => AocError::InvalidCookie,
+            _ => AocError::ServerError(status),
+        }
+    }
+
+    //`action` is what was being done to `path`, like "read" or "write"
+    pub fn io(action: &str, path: &Path, e: io::Error) -> AocError {
+        AocError::Io(format!("Could not {} {}: {}", action, path.display(), e))
+    }
+
+    //2 is taken by clap for usage errors
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            AocError::MissingCookie => 3,
+            AocError::InvalidCookie => 4,
+            AocError::NotYetAvailable => 5,
+            AocError::ServerError(_) => 6,
+            AocError::Network(_) => 7,
+            AocError::Io(_) => 8,
+            AocError::NoYear => 9,
+            AocError::NoSolution(_) => 10,
+            AocError::Input(_) => 11,
+            AocError::Leaderboard(_) => 12,
+            AocError::Config(_) => 13,
+            AocError::NoPuzzle(_) => 14,
+            AocError::Mismatch(_) => 15,
+            AocError::Bench(_) => 16,
+            AocError::Refused(_) => 17,
+            AocError::NotAccepted(_) => 18,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_status() {
+        assert_eq!(AocError::from_status(404, "Please don't repeatedly request this endpoint before it unlocks!"), AocError::NotYetAvailable);
+        assert_eq!(AocError::from_status(400, "Puzzle inputs differ by user.  Please log in to get your puzzle input."), AocError::InvalidCookie);
+        assert_eq!(AocError::from_status(500, "Internal Server Error"), AocError::ServerError(500));
+    }
+
+    #[test]
+    fn test_exit_codes_are_distinct() {
+        let errors = [
+            AocError::MissingCookie,
+            AocError::InvalidCookie,
+            AocError::NotYetAvailable,
+            AocError::ServerError(500),
+            AocError::Network(String::new()),
+            AocError::Io(String::new()),
+            AocError::NoYear,
+            AocError::NoSolution(String::new()),
+            AocError::Input(String::new()),
+            AocError::Leaderboard(String::new()),
+            AocError::Config(String::new()),
+            AocError::NoPuzzle(String::new()),
+            AocError::Mismatch(0),
+            AocError::Bench(String::new()),
+            AocError::Refused(String::new()),
+            AocError::NotAccepted(String::new()),
+        ];
+        let mut codes: Vec<i32> = errors.iter().map(|e| e.exit_code()).collect();
+        codes.sort();
+        codes.dedup();
+        assert_eq!(codes.len(), errors.len());
+    }
+}
@@ -0,0 +1,108 @@
+use std::io::{self, Read};
+use std::path::PathBuf;
+use crate::config::Config;
+
+//where a day's input is read from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    //the day's file in the configured input directory
+    Config,
+    Path(PathBuf),
+    Stdin,
+}
+
+impl Source {
+    //the value of an --input flag: a path, `-` for stdin, or nothing for the configured file
+    pub fn from_arg(arg: Option<&str>) -> Source {
+        match arg {
+            None => Source::Config,
+            Some("-") => Source::Stdin,
+            Some(path) => Source::Path(PathBuf::from(path)),
+        }
+    }
+}
+
+//one day's input, so any solution can run on someone else's without recompiling
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Input {
+    pub year: u32,
+    pub day: u32,
+    pub source: Source,
+}
+
+impl Input {
+    pub fn new(year: u32, day: u32, source: Source) -> Input {
+        Input { year, day, source }
+    }
+
+    //`--input <path>`, `--input=<path>` or `--input -` among a day binary's arguments
+    pub fn from_args(year: u32, day: u32, args: impl IntoIterator<Item = String>) -> Input {
+        let mut args = args.into_iter();
+        let mut input = None;
+        while let Some(arg) = args.next() {
+            if arg == "--input" {
+                input = args.next();
+            } else if let Some(value) = arg.strip_prefix("--input=") {
+                input = Some(value.to_string());
+            }
+        }
+        Input::new(year, day, Source::from_arg(input.as_deref()))
+    }
+
+    pub fn read(&self, config: &Config) -> io::Result<String> {
+        match &self.source {
+            Source::Config => config.read_input(self.year, self.day),
+            Source::Path(path) => std::fs::read_to_string(path),
+            Source::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                Ok(input)
+            }
+        }
+    }
+
+    pub fn describe(&self, config: &Config) -> String {
+        match &self.source {
+            Source::Config => config.input_path(self.year, self.day).display().to_string(),
+            Source::Path(path) => path.display().to_string(),
+            Source::Stdin => "stdin".to_string(),
+        }
+    }
+}
+
+//the input for a day's own binary, from --input or wherever aoc.toml says, from anywhere in the project
+pub fn load(year: u32, day: u32) -> String {
+    let config = Config::load().unwrap_or_else(|e| panic!("{}", e));
+    let input = Input::from_args(year, day, std::env::args().skip(1));
+    match input.read(&config) {
+        Ok(text) => text,
+        Err(e) => panic!("Could not read input {}: {}", input.describe(&config), e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|a| a.to_string()).collect()
+    }
+
+    #[test]
+    fn test_from_args() {
+        assert_eq!(Input::from_args(2022, 1, args(&[])).source, Source::Config);
+        assert_eq!(Input::from_args(2022, 1, args(&["--input", "-"])).source, Source::Stdin);
+        assert_eq!(Input::from_args(2022, 1, args(&["--input", "theirs.txt"])).source, Source::Path(PathBuf::from("theirs.txt")));
+        assert_eq!(Input::from_args(2022, 1, args(&["--input=theirs.txt"])).source, Source::Path(PathBuf::from("theirs.txt")));
+    }
+
+    #[test]
+    fn test_read() {
+        let config = Config::default();
+        let from_config = Input::new(2022, 1, Source::Config);
+        let from_path = Input::new(2022, 1, Source::Path(PathBuf::from("inputs/2022_1.txt")));
+        assert_eq!(from_config.read(&config).unwrap(), from_path.read(&config).unwrap());
+        assert_eq!(from_config.describe(&config), "inputs/2022_1.txt");
+        assert!(Input::new(2022, 1, Source::Path(PathBuf::from("missing.txt"))).read(&config).is_err());
+    }
+}
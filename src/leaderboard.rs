@@ -0,0 +1,223 @@
+use std::collections::BTreeMap;
+use std::time::Duration;
+use chrono::{TimeZone, Utc};
+use aoc::config::Config;
+use serde::{Deserialize, Deserializer};
+use crate::client::{Cache, Client};
+use crate::error::AocError;
+use crate::profile::session_cookie;
+use crate::run::table;
+use crate::unlock;
+
+//the site asks that leaderboards are fetched at most once every 15 minutes
+const REFRESH: Duration = Duration::from_secs(15 * 60);
+
+#[derive(Debug, Deserialize)]
+pub struct Star {
+    pub get_star_ts: i64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Member {
+    pub id: u64,
+    pub name: Option<String>,
+    pub stars: u32,
+    pub local_score: u32,
+    pub last_star_ts: i64,
+    //day -> part -> when the star was earned, both keyed by strings in the json
+    #[serde(default)]
+    pub completion_day_level: BTreeMap<String, BTreeMap<String, Star>>,
+}
+
+//the json has the year as a string
+fn year<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u32, D::Error> {
+    String::deserialize(deserializer)?.parse().map_err(serde::de::Error::custom)
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Leaderboard {
+    #[serde(deserialize_with = "year")]
+    pub event: u32,
+    pub members: BTreeMap<String, Member>,
+}
+
+impl Member {
+    //members can hide their name, the site shows them like this instead
+    pub fn display_name(&self) -> String {
+        match &self.name {
+            Some(name) => name.clone(),
+            None => format!("(anonymous user #{})", self.id),
+        }
+    }
+
+    pub fn star_time(&self, day: u32, part: u32) -> Option<i64> {
+        self.completion_day_level.get(&day.to_string())?.get(&part.to_string()).map(|s| s.get_star_ts)
+    }
+
+    fn day_stars(&self, day: u32) -> usize {
+        (1..=2).filter(|part| self.star_time(day, *part).is_some()).count()
+    }
+}
+
+impl Leaderboard {
+    //highest score first, ties go to whoever got their last star earlier, like on the site
+    pub fn ranked(&self) -> Vec<&Member> {
+        let mut members: Vec<&Member> = self.members.values().collect();
+        members.sort_by_key(|m| (std::cmp::Reverse(m.local_score), m.last_star_ts, m.id));
+        members
+    }
+
+    fn last_day(&self) -> u32 {
+        (1..=25).rev()
+            .find(|day| self.members.values().any(|m| m.day_stars(*day) > 0))
+            .unwrap_or(1)
+    }
+}
+
+fn format_timestamp(timestamp: i64) -> String {
+    match Utc.timestamp_opt(timestamp, 0).single() {
+        Some(time) if timestamp > 0 => time.format("%b %d %H:%M:%S").to_string(),
+        _ => "-".to_string(),
+    }
+}
+
+//when the star was earned, and how long after the day unlocked
+fn format_star(year: u32, day: u32, timestamp: Option<i64>) -> String {
+    let timestamp = match timestamp {
+        Some(timestamp) => timestamp,
+        None => return String::new(),
+    };
+    let after = unlock::unlock_time(year, day)
+        .and_then(|unlock| Utc.timestamp_opt(timestamp, 0).single().map(|t| t - unlock))
+        .and_then(|after| after.to_std().ok())
+        .unwrap_or_default();
+    format!("{} (+{})", format_timestamp(timestamp), unlock::format_countdown(after))
+}
+
+//* for both stars of a day, + for only the first
+fn star_row(member: &Member, last_day: u32) -> String {
+    (1..=last_day)
+        .map(|day| match member.day_stars(day) {
+            2 => '*',
+            1 => '+',
+            _ => '.',
+        })
+        .collect()
+}
+
+pub fn render(leaderboard: &Leaderboard) -> String {
+    let year = leaderboard.event;
+    let last_day = leaderboard.last_day();
+    let days: String = (1..=last_day).map(|day| char::from_digit(day % 10, 10).unwrap()).collect();
+
+    let mut rows = vec![["", "name", "score", "stars", &days, "last star"].map(String::from).to_vec()];
+    for (rank, member) in leaderboard.ranked().iter().enumerate() {
+        rows.push(vec![
+            format!("{})", rank + 1),
+            member.display_name(),
+            member.local_score.to_string(),
+            member.stars.to_string(),
+            star_row(member, last_day),
+            format_timestamp(member.last_star_ts),
+        ]);
+    }
+    let mut s = format!("Leaderboard {}\n\n", year);
+    s.push_str(&table(&rows));
+
+    for day in 1..=last_day {
+        //who finished first, then who only has the first star
+        let mut finished: Vec<&Member> = leaderboard.members.values().filter(|m| m.day_stars(day) > 0).collect();
+        if finished.is_empty() {
+            continue;
+        }
+        finished.sort_by_key(|m| (m.star_time(day, 2).is_none(), m.star_time(day, 2), m.star_time(day, 1), m.id));
+        let rows: Vec<Vec<String>> = finished.iter()
+            .map(|m| vec![
+                format!("  {}", m.display_name()),
+                format_star(year, day, m.star_time(day, 1)),
+                format_star(year, day, m.star_time(day, 2)),
+            ])
+            .collect();
+        s.push_str(&format!("\nDay {}\n", day));
+        s.push_str(&table(&rows));
+    }
+    s
+}
+
+fn is_leaderboard(body: &str) -> bool {
+    serde_json::from_str::<Leaderboard>(body).is_ok()
+}
+
+pub async fn leaderboard(config: &Config, id: u32) -> Result<(), AocError> {
+    let year = config.year.unwrap_or_else(|| unlock::latest_event(Utc::now()));
+    let profile = config.profile.as_str();
+    let client = Client::new(&config.root, session_cookie(profile)?)?;
+    //the site sends anyone who can't see the leaderboard back to its front page, which is never cached
+    let body = client.get(&format!("/{}/leaderboard/private/view/{}.json", year, id), Cache::For(REFRESH, is_leaderboard)).await?;
+    let leaderboard = serde_json::from_str::<Leaderboard>(&body).map_err(|_| AocError::Leaderboard(
+        format!("Could not read leaderboard {} for {}, check the id and that profile {} is a member", id, year, profile)))?;
+    print!("{}", render(&leaderboard));
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_leaderboard() -> Leaderboard {
+        serde_json::from_str(include_str!("../tests/pages/leaderboard.json")).unwrap()
+    }
+
+    #[test]
+    fn test_ranked() {
+        let leaderboard = test_leaderboard();
+        let names: Vec<String> = leaderboard.ranked().iter().map(|m| m.display_name()).collect();
+        assert_eq!(names, vec!["Ada", "(anonymous user #5678)", "Grace"]);
+    }
+
+    #[test]
+    fn test_star_time() {
+        let leaderboard = test_leaderboard();
+        let ada = &leaderboard.members["1234"];
+        assert_eq!(ada.star_time(1, 2), Some(1669871400));
+        assert_eq!(ada.star_time(3, 1), None);
+        assert_eq!(star_row(&leaderboard.members["5678"], 3), "*+.");
+    }
+
+    #[test]
+    fn test_event_must_be_a_year() {
+        let json = include_str!("../tests/pages/leaderboard.json").replace(r#""event":"2022""#, r#""event":"twenty""#);
+        assert!(!is_leaderboard(&json));
+        assert!(!is_leaderboard("<html><body>Advent of Code</body></html>"));
+        assert!(is_leaderboard(include_str!("../tests/pages/leaderboard.json")));
+    }
+
+    #[test]
+    fn test_format_star() {
+        assert_eq!(format_star(2022, 1, Some(1669871100)), "Dec 01 05:05:00 (+0h 05m 00s)");
+        assert_eq!(format_star(2022, 2, Some(1670044000)), "Dec 03 05:06:40 (+1d 0h 6m 40s)");
+        assert_eq!(format_star(2022, 1, None), "");
+    }
+
+    #[test]
+    fn test_render() {
+        let output = render(&test_leaderboard());
+        let expected = "\
+Leaderboard 2022
+
+    name                    score  stars  12  last star
+1)  Ada                     14     4      **  Dec 03 05:06:40
+2)  (anonymous user #5678)  11     3      *+  Dec 02 05:15:00
+3)  Grace                   0      0      ..  -
+
+Day 1
+  (anonymous user #5678)  Dec 01 05:01:40 (+0h 01m 40s)  Dec 01 05:06:40 (+0h 06m 40s)
+  Ada                     Dec 01 05:05:00 (+0h 05m 00s)  Dec 01 05:10:00 (+0h 10m 00s)
+
+Day 2
+  Ada                     Dec 02 05:10:00 (+0h 10m 00s)  Dec 03 05:06:40 (+1d 0h 6m 40s)
+  (anonymous user #5678)  Dec 02 05:15:00 (+0h 15m 00s)
+";
+        assert_eq!(output, expected);
+    }
+}
@@ -0,0 +1,160 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use num_bigint::BigInt;
+use serde::{Deserialize, Serialize};
+use aoc::answer::Answer;
+use crate::error::AocError;
+use crate::submit::Verdict;
+
+//under the project root, next to the client's cache
+const LEDGER_FILE: &str = ".aoc/ledger.toml";
+
+//one answer sent to the site and what it said
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Submission {
+    pub year: u32,
+    pub day: u32,
+    pub part: u32,
+    pub profile: String,
+    pub answer: Answer,
+    pub verdict: String,
+    pub at: String,
+}
+
+//every answer submitted from this checkout, so known-bad ones never cost another cooldown
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Ledger {
+    #[serde(default)]
+    pub submissions: Vec<Submission>,
+}
+
+//how a verdict is written in the ledger
+pub fn verdict_kind(verdict: &Verdict) -> &'static str {
+    match verdict {
+        Verdict::Correct => "correct",
+        Verdict::TooHigh => "too high",
+        Verdict::TooLow => "too low",
+        Verdict::Wrong => "wrong",
+        Verdict::RateLimited(_) => "rate limited",
+        Verdict::AlreadySolved => "already solved",
+        Verdict::Unknown(_) => "unknown",
+    }
+}
+
+fn ledger_path(root: &Path) -> PathBuf {
+    root.join(LEDGER_FILE)
+}
+
+impl Ledger {
+    //a ledger that can't be read is an error, starting over would forget the answers it rules out
+    pub fn load(root: &Path) -> Result<Ledger, AocError> {
+        let path = ledger_path(root);
+        match fs::read_to_string(&path) {
+            Ok(text) => toml::from_str(&text)
+                .map_err(|e| AocError::Io(format!("Could not read {}: {}", path.display(), e))),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Ledger::default()),
+            Err(e) => Err(AocError::io("read", &path, e)),
+        }
+    }
+
+    pub fn save(&self, root: &Path) -> Result<(), AocError> {
+        let path = ledger_path(root);
+        let dir = path.parent().unwrap();
+        fs::create_dir_all(dir).map_err(|e| AocError::io("create", dir, e))?;
+        fs::write(&path, toml::to_string(self).unwrap()).map_err(|e| AocError::io("write", &path, e))
+    }
+
+    pub fn record(&mut self, profile: &str, year: u32, day: u32, part: u32, answer: &Answer, verdict: &Verdict) {
+        self.submissions.push(Submission {
+            year,
+            day,
+            part,
+            profile: profile.to_string(),
+            answer: answer.clone(),
+            verdict: verdict_kind(verdict).to_string(),
+            at: chrono::Utc::now().to_rfc3339(),
+        });
+    }
+
+    fn history<'a>(&'a self, profile: &'a str, year: u32, day: u32, part: u32) -> impl Iterator<Item = &'a Submission> {
+        self.submissions.iter()
+            .filter(move |s| s.profile == profile && s.year == year && s.day == day && s.part == part)
+    }
+
+    //the lowest answer known to be too high and the highest known to be too low
+    pub fn bounds<'a>(&'a self, profile: &'a str, year: u32, day: u32, part: u32) -> (Option<&'a BigInt>, Option<&'a BigInt>) {
+        let numbers = |kind: &'static str| self.history(profile, year, day, part)
+            .filter(move |s| s.verdict == kind)
+            .filter_map(|s| s.answer.as_number());
+        (numbers("too low").max(), numbers("too high").min())
+    }
+
+    //why the site would certainly turn this answer down, if it would
+    pub fn check(&self, profile: &str, year: u32, day: u32, part: u32, answer: &Answer) -> Option<String> {
+        let history: Vec<&Submission> = self.history(profile, year, day, part).collect();
+        if let Some(correct) = history.iter().find(|s| s.verdict == "correct") {
+            return Some(format!("{} day {} part {} was already solved with {}", year, day, part, correct.answer));
+        }
+        let judged = ["too high", "too low", "wrong"];
+        if let Some(seen) = history.iter().find(|s| s.answer == *answer && judged.contains(&s.verdict.as_str())) {
+            return Some(format!("{} was already submitted on {} and was {}", answer, seen.at, seen.verdict));
+        }
+        let value = answer.as_number()?;
+        match self.bounds(profile, year, day, part) {
+            (_, Some(high)) if value >= high => Some(format!("{} is not below {}, which was too high", answer, high)),
+            (Some(low), _) if value <= low => Some(format!("{} is not above {}, which was too low", answer, low)),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_ledger() -> Ledger {
+        let mut ledger = Ledger::default();
+        ledger.record("default", 2022, 1, 1, &Answer::from("100"), &Verdict::TooLow);
+        ledger.record("default", 2022, 1, 1, &Answer::from("500"), &Verdict::TooHigh);
+        ledger.record("default", 2022, 1, 1, &Answer::from("300"), &Verdict::TooHigh);
+        ledger.record("default", 2022, 1, 1, &Answer::from("250"), &Verdict::Wrong);
+        ledger.record("default", 2022, 1, 1, &Answer::from("260"), &Verdict::RateLimited(std::time::Duration::from_secs(30)));
+        ledger
+    }
+
+    #[test]
+    fn test_bounds() {
+        let ledger = test_ledger();
+        assert_eq!(ledger.bounds("default", 2022, 1, 1), (Some(&BigInt::from(100)), Some(&BigInt::from(300))));
+        assert_eq!(ledger.bounds("default", 2022, 1, 2), (None, None));
+        assert_eq!(ledger.bounds("work", 2022, 1, 1), (None, None));
+    }
+
+    #[test]
+    fn test_check() {
+        let ledger = test_ledger();
+        assert!(ledger.check("default", 2022, 1, 1, &Answer::from("250")).unwrap().contains("was wrong"));
+        assert_eq!(ledger.check("default", 2022, 1, 1, &Answer::from("350")).unwrap(), "350 is not below 300, which was too high");
+        assert_eq!(ledger.check("default", 2022, 1, 1, &Answer::from("99")).unwrap(), "99 is not above 100, which was too low");
+        assert_eq!(ledger.check("default", 2022, 1, 1, &Answer::from("200")), None);
+        //a rate limited answer was never judged
+        assert_eq!(ledger.check("default", 2022, 1, 1, &Answer::from("260")), None);
+        assert_eq!(ledger.check("default", 2022, 1, 1, &Answer::from("abc")), None);
+        assert_eq!(ledger.check("work", 2022, 1, 1, &Answer::from("250")), None);
+    }
+
+    #[test]
+    fn test_check_after_correct() {
+        let mut ledger = test_ledger();
+        ledger.record("default", 2022, 1, 1, &Answer::from("200"), &Verdict::Correct);
+        assert_eq!(ledger.check("default", 2022, 1, 1, &Answer::from("201")).unwrap(), "2022 day 1 part 1 was already solved with 200");
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let ledger = test_ledger();
+        let parsed: Ledger = toml::from_str(&toml::to_string(&ledger).unwrap()).unwrap();
+        assert_eq!(parsed.submissions, ledger.submissions);
+    }
+}
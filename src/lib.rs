@@ -0,0 +1,12 @@
+pub mod answer;
+pub mod config;
+pub mod input;
+pub mod solution;
+pub mod util;
+//days keep their tests at the top, above the solution, like the template
+#[allow(clippy::items_after_test_module)]
+pub mod y2015;
+#[allow(clippy::items_after_test_module)]
+pub mod y2021;
+#[allow(clippy::items_after_test_module)]
+pub mod y2022;
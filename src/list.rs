@@ -0,0 +1,82 @@
+use std::fs::{self, read_to_string};
+use aoc::config::Config;
+use aoc::solution;
+
+struct DayStatus {
+    parts: u32,
+    input: bool,
+    tests: bool,
+}
+
+fn day_status(config: &Config, year: u32, day: u32) -> DayStatus {
+    let parts = solution::find(year, day).map(|s| s.parts()).unwrap_or(0);
+    //`aoc new` leaves an empty file until the input is pulled
+    let input = fs::metadata(config.input_path(year, day)).is_ok_and(|m| m.len() > 0);
+    //either tests in the day's module or example fixtures for the shared harness
+    let tests = read_to_string(config.root.join(format!("src/y{}/day{}.rs", year, day)))
+        .map(|source| source.contains("#[test]"))
+        .unwrap_or(false)
+        || config.root.join(format!("fixtures/{}_{}", year, day)).exists();
+    DayStatus { parts, input, tests }
+}
+
+fn mark(present: bool) -> char {
+    if present { '#' } else { '.' }
+}
+
+impl DayStatus {
+    fn cells(&self) -> [char; 3] {
+        let parts = if self.parts == 0 { '.' } else { char::from_digit(self.parts, 10).unwrap() };
+        [parts, mark(self.input), mark(self.tests)]
+    }
+}
+
+//one column per day, one row each for solved parts, input file and example tests
+fn render(year: u32, days: &[DayStatus]) -> String {
+    let mut s = format!("{:<6}", year);
+    for day in 1..=days.len() {
+        s.push_str(&format!("{:>3}", day));
+    }
+    s.push('\n');
+    for (row, name) in ["parts", "input", "tests"].iter().enumerate() {
+        s.push_str(&format!("{:<6}", name));
+        for day in days {
+            s.push_str(&format!("{:>3}", day.cells()[row]));
+        }
+        s.push('\n');
+    }
+    s
+}
+
+pub fn list(config: &Config) {
+    let mut years: Vec<u32> = solution::all().iter().map(|s| s.year()).collect();
+    years.dedup();
+    let output = years.iter()
+        .map(|year| {
+            let days: Vec<DayStatus> = (1..=25).map(|day| day_status(config, *year, day)).collect();
+            render(*year, &days)
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    print!("{}", output);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        let days = vec![
+            DayStatus { parts: 2, input: true, tests: true },
+            DayStatus { parts: 1, input: true, tests: false },
+            DayStatus { parts: 0, input: false, tests: false },
+        ];
+        assert_eq!(render(2022, &days),
+"2022    1  2  3
+parts   2  1  .
+input   #  #  .
+tests   #  .  .
+");
+    }
+}
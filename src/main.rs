@@ -1,42 +1,249 @@
+use std::path::PathBuf;
+use aoc::config::{Config, Format};
+use aoc::input::{Input, Source};
 use clap::{Parser, Subcommand};
-use tokio;
+use crate::error::AocError;
 
+mod answers;
+mod bench;
+mod calendar;
+mod client;
+mod error;
+mod leaderboard;
+mod ledger;
+mod list;
+mod new;
+mod profile;
 mod pull;
+mod puzzle;
+mod run;
+mod submit;
+mod unlock;
 
 #[derive(Parser)]
 #[command(author, version, about, long_about = None)]
 struct Cli {
     #[command(subcommand)]
     command: Option<Commands>,
+    //which account's session and inputs to use
+    #[arg(long, global = true)]
+    profile: Option<String>,
+    //the year to use when a command is only given a day
+    #[arg(long = "year", id = "default_year", global = true)]
+    default_year: Option<u32>,
+    #[arg(long, global = true)]
+    input_dir: Option<PathBuf>,
+    #[arg(long, global = true)]
+    puzzle_dir: Option<PathBuf>,
+    #[arg(long, global = true)]
+    answers_file: Option<PathBuf>,
+    #[arg(long, global = true)]
+    bench_dir: Option<PathBuf>,
+    #[arg(long, global = true, value_enum)]
+    format: Option<Format>,
+}
+
+impl Cli {
+    //aoc.toml, with whatever was given on the command line taking precedence
+    fn config(&self) -> Result<Config, AocError> {
+        let mut config = Config::load().map_err(AocError::Config)?;
+        config.year = self.default_year.or(config.year);
+        config.profile = self.profile.clone().unwrap_or(config.profile);
+        config.input_dir = self.input_dir.clone().unwrap_or(config.input_dir);
+        config.puzzle_dir = self.puzzle_dir.clone().unwrap_or(config.puzzle_dir);
+        config.answers_file = self.answers_file.clone().unwrap_or(config.answers_file);
+        config.bench_dir = self.bench_dir.clone().unwrap_or(config.bench_dir);
+        config.format = self.format.unwrap_or(config.format);
+        Ok(config)
+    }
+}
+
+//`2022 5` is day 5 of 2022, a lone `5` is day 5 of the default year
+fn year_day(config: &Config, first: u32, second: Option<u32>) -> Result<(u32, u32), AocError> {
+    match (second, config.year) {
+        (Some(day), _) => Ok((first, day)),
+        (None, Some(year)) => Ok((year, first)),
+        (None, None) => Err(AocError::NoYear),
+    }
 }
 
 #[derive(Subcommand)]
 enum Commands {
-    //pull the quesiton and input for a given AoC
+    //save a session cookie for the profile after checking it with the site
+    Login {
+        // session: Option<String>, asked for when missing
+        session: Option<String>,
+    },
+    //pull the question and input for a given AoC
     Pull {
-        // year: u32
+        // year: u32, or the day when there is a default year
+        #[arg(required_unless_present = "all")]
+        year: Option<u32>,
+        // day: Option<u32>
+        day: Option<u32>,
+        //pull every unlocked day of the year
+        #[arg(long, conflicts_with_all = ["day", "wait"])]
+        all: bool,
+        //how many days --all pulls at once
+        #[arg(long, default_value_t = 4)]
+        concurrency: usize,
+        //download the input again even if it is already saved
+        #[arg(long)]
+        force: bool,
+        //sleep until the day unlocks instead of giving up
+        #[arg(long)]
+        wait: bool,
+    },
+    //extract the puzzle's examples and expected answers into fixtures/
+    Examples {
+        // year: u32, or the day when there is a default year
         year: u32,
-        // day: u32
-        day: u32,
+        // day: Option<u32>
+        day: Option<u32>,
+        //overwrite fixtures that already exist
+        #[arg(long)]
+        force: bool,
     },
+    //run both parts of a solution against its input
     Run {
+        // year: u32, or the day when there is a default year
+        #[arg(required_unless_present = "all")]
+        year: Option<u32>,
+        // day: Option<u32>
+        day: Option<u32>,
+        //run every day of the year, or of every year when no year is given
+        #[arg(long, conflicts_with = "day")]
+        all: bool,
+        //read the input from this file instead, or from stdin with `-`
+        #[arg(long, conflicts_with = "all")]
+        input: Option<String>,
+    },
+    //benchmark parsing and each part on the real input, and compare with the last saved run
+    Bench {
+        // year: u32, or the day when there is a default year
+        year: u32,
+        // day: Option<u32>
+        day: Option<u32>,
+        //only benchmark this part
+        #[arg(long)]
+        part: Option<u32>,
+        //compare with the last run of this name, then save this run under it
+        #[arg(long, default_value = bench::DEFAULT_BASELINE)]
+        save_baseline: String,
+        //compare with this saved run instead, without replacing it
+        #[arg(long, conflicts_with = "save_baseline")]
+        baseline: Option<String>,
+        //fewer and shorter samples, for a rough number
+        #[arg(long)]
+        quick: bool,
+    },
+    //create a day's solution file, binary and empty input from the template
+    New {
+        // year: u32, or the day when there is a default year
+        year: u32,
+        // day: Option<u32>
+        day: Option<u32>,
+    },
+    //submit an answer, solving the part first when no answer is given
+    Submit {
         // year: u32
         year: u32,
         // day: u32
         day: u32,
+        // part: u32, 1 or 2
+        #[arg(value_parser = clap::value_parser!(u32).range(1..=2))]
+        part: u32,
+        // answer: Option<String>
+        answer: Option<String>,
+    },
+    //rerun the solutions and check them against the answers the site accepted
+    Verify {
+        // year: Option<u32>, every recorded year when not given
+        year: Option<u32>,
+        // day: Option<u32>, every recorded day of the year when not given
+        day: Option<u32>,
+    },
+    //show which days have solutions, inputs and tests
+    List,
+    //show the stars earned on the site next to the solved parts here
+    Calendar {
+        // year: Option<u32>, the default or latest year when not given
+        year: Option<u32>,
+    },
+    //show a private leaderboard's ranking and who finished each day when, for --year or the latest event
+    Leaderboard {
+        // id: u32, the number at the end of the leaderboard's url
+        id: u32,
     },
 }
 
-#[tokio::main]
-async fn main() {
-    let cli = Cli::parse();
+//main prints the error and exits with its code
+async fn execute(cli: &Cli, config: &Config) -> Result<(), AocError> {
     match &cli.command {
-        Some(Commands::Pull { year, day }) => {
-            pull::pull(*year, *day).await;
+        Some(Commands::Login { session }) => {
+            profile::login(&config.root, &config.profile, session.clone()).await
+        }
+        Some(Commands::Pull { year, all, concurrency, force, .. }) if *all => {
+            let year = year.or(config.year).ok_or(AocError::NoYear)?;
+            pull::pull_all(config, year, *force, *concurrency).await
+        }
+        Some(Commands::Pull { year, day, force, wait, .. }) => {
+            let (year, day) = year_day(config, year.unwrap(), *day)?;
+            pull::pull(config, year, day, *force, *wait).await
+        }
+        Some(Commands::Examples { year, day, force }) => {
+            let (year, day) = year_day(config, *year, *day)?;
+            pull::examples(config, year, day, *force).await
+        }
+        Some(Commands::Run { year, day, all, input }) => {
+            if *all {
+                run::run_all(config, *year)
+            } else {
+                let (year, day) = year_day(config, year.unwrap(), *day)?;
+                run::run(config, &Input::new(year, day, Source::from_arg(input.as_deref())))
+            }
+        }
+        Some(Commands::Bench { year, day, part, save_baseline, baseline, quick }) => {
+            let (year, day) = year_day(config, *year, *day)?;
+            let baseline = match baseline {
+                Some(name) => bench::Baseline::Compare(name.clone()),
+                None => bench::Baseline::Save(save_baseline.clone()),
+            };
+            bench::bench(config, year, day, *part, &baseline, *quick)
+        }
+        Some(Commands::New { year, day }) => {
+            let (year, day) = year_day(config, *year, *day)?;
+            new::new(config, year, day)
+        }
+        Some(Commands::Submit { year, day, part, answer }) => {
+            submit::submit(config, *year, *day, *part, answer.clone()).await
         }
-        Some(Commands::Run { year, day }) => {
-            println!("run {} {}", year, day);
+        Some(Commands::Verify { year, day }) => {
+            answers::verify(config, *year, *day)
         }
-        None => {}
+        Some(Commands::List) => {
+            list::list(config);
+            Ok(())
+        }
+        Some(Commands::Calendar { year }) => {
+            calendar::calendar(config, *year).await
+        }
+        Some(Commands::Leaderboard { id }) => {
+            leaderboard::leaderboard(config, *id).await
+        }
+        None => Ok(()),
+    }
+}
+
+#[tokio::main]
+async fn main() {
+    let cli = Cli::parse();
+    let result = match cli.config() {
+        Ok(config) => execute(&cli, &config).await,
+        Err(e) => Err(e),
+    };
+    if let Err(e) = result {
+        eprintln!("{}", e);
+        std::process::exit(e.exit_code());
     }
 }
@@ -0,0 +1,158 @@
+use std::fs;
+use std::path::Path;
+use aoc::config::Config;
+use crate::error::AocError;
+
+const DAY_TEMPLATE: &str = include_str!("../templates/day.rs.tmpl");
+const BIN_TEMPLATE: &str = include_str!("../templates/bin.rs.tmpl");
+const YEAR_TEMPLATE: &str = include_str!("../templates/year.rs.tmpl");
+const MANIFEST_TEMPLATE: &str = include_str!("../templates/bin.toml.tmpl");
+//on each year's module in lib.rs, since days keep their tests above the solution
+const YEAR_ATTRIBUTE: &str = "#[allow(clippy::items_after_test_module)]";
+
+fn render(template: &str, year: u32, day: u32) -> String {
+    template
+        .replace("{year}", &year.to_string())
+        .replace("{day}", &day.to_string())
+}
+
+fn first_number(line: &str) -> u32 {
+    line.chars()
+        .skip_while(|c| !c.is_ascii_digit())
+        .take_while(|c| c.is_ascii_digit())
+        .collect::<String>()
+        .parse()
+        .unwrap_or(0)
+}
+
+//insert `line` among the lines starting with `prefix`, keeping them ordered by the number they contain,
+//`line` goes above the attributes of the line it is put before
+fn insert_sorted(text: &str, prefix: &str, line: &str) -> String {
+    let mut lines: Vec<&str> = text.lines().collect();
+    let matching: Vec<usize> = (0..lines.len()).filter(|i| lines[*i].starts_with(prefix)).collect();
+    let mut position = match matching.iter().find(|i| first_number(lines[**i]) > first_number(line)) {
+        Some(position) => *position,
+        None => matching.last().map(|i| i + 1).unwrap_or(lines.len()),
+    };
+    while matching.contains(&position) && position > 0 && lines[position - 1].starts_with("#[") {
+        position -= 1;
+    }
+    lines.insert(position, line);
+    lines.join("\n") + "\n"
+}
+
+fn write(path: &Path, text: &str) -> Result<(), AocError> {
+    fs::write(path, text).map_err(|e| AocError::io("write", path, e))
+}
+
+fn edit(path: &Path, prefix: &str, line: &str) -> Result<(), AocError> {
+    let text = fs::read_to_string(path).map_err(|e| AocError::io("read", path, e))?;
+    write(path, &insert_sorted(&text, prefix, line))
+}
+
+fn register(root: &Path, year: u32, day: u32) -> Result<(), AocError> {
+    let year_mod = root.join(format!("src/y{}/mod.rs", year));
+    if year_mod.exists() {
+        edit(&year_mod, "pub mod day", &format!("pub mod day{};", day))?;
+        edit(&year_mod, "        &day", &format!("        &day{}::Day{},", day, day))
+    } else {
+        write(&year_mod, &render(YEAR_TEMPLATE, year, day))?;
+        edit(&root.join("src/lib.rs"), "pub mod y", &format!("{}\npub mod y{};", YEAR_ATTRIBUTE, year))?;
+        edit(&root.join("src/solution.rs"), "    solutions.extend(crate::y", &format!("    solutions.extend(crate::y{}::solutions());", year))
+    }
+}
+
+//day binaries only build with `--features bins`, so each one is declared in Cargo.toml
+fn register_bin(root: &Path, year: u32, day: u32) -> Result<(), AocError> {
+    let path = root.join("Cargo.toml");
+    let mut manifest = fs::read_to_string(&path).map_err(|e| AocError::io("read", &path, e))?;
+    manifest.push_str(&render(MANIFEST_TEMPLATE, year, day));
+    write(&path, &manifest)
+}
+
+//paths are under the directory holding aoc.toml, so this works from anywhere in the project
+pub fn new(config: &Config, year: u32, day: u32) -> Result<(), AocError> {
+    if !(1..=25).contains(&day) || year < 2015 {
+        return Err(AocError::NoPuzzle(format!("{} day {}", year, day)));
+    }
+    let year_dir = config.root.join(format!("src/y{}", year));
+    let day_path = year_dir.join(format!("day{}.rs", day));
+    let bin_path = config.root.join(format!("src/bin/{}_{}.rs", year, day));
+    let input_path = config.input_path(year, day);
+    for path in [&day_path, &bin_path] {
+        if path.exists() {
+            return Err(AocError::Io(format!("{} already exists, not overwriting it", path.display())));
+        }
+    }
+
+    fs::create_dir_all(&year_dir).map_err(|e| AocError::io("create", &year_dir, e))?;
+    write(&day_path, &render(DAY_TEMPLATE, year, day))?;
+    write(&bin_path, &render(BIN_TEMPLATE, year, day))?;
+    register(&config.root, year, day)?;
+    register_bin(&config.root, year, day)?;
+    println!("Created {} and {}", day_path.display(), bin_path.display());
+
+    //an input may already have been pulled, keep it
+    if !input_path.exists() {
+        let input_dir = input_path.parent().unwrap_or(&config.root);
+        fs::create_dir_all(input_dir).map_err(|e| AocError::io("create", input_dir, e))?;
+        write(&input_path, "")?;
+        println!("Created {}", input_path.display());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_input() -> &'static str {
+"use crate::solution::DynSolution;
+
+pub mod day1;
+pub mod day2;
+pub mod day10;
+
+pub fn solutions() -> Vec<&'static dyn DynSolution> {
+    vec![
+        &day1::Day1,
+        &day2::Day2,
+        &day10::Day10,
+    ]
+}
+"
+    }
+
+    #[test]
+    fn test_insert_sorted() {
+        let text = insert_sorted(test_input(), "pub mod day", "pub mod day9;");
+        let text = insert_sorted(&text, "        &day", "        &day9::Day9,");
+        assert!(text.contains("pub mod day2;\npub mod day9;\npub mod day10;\n"));
+        assert!(text.contains("&day2::Day2,\n        &day9::Day9,\n        &day10::Day10,\n"));
+    }
+
+    #[test]
+    fn test_insert_last() {
+        let text = insert_sorted(test_input(), "pub mod day", "pub mod day11;");
+        assert!(text.contains("pub mod day10;\npub mod day11;\n\npub fn"));
+    }
+
+    #[test]
+    fn test_insert_before_attributes() {
+        let lib = "pub mod util;\n#[allow(x)]\npub mod y2015;\n#[allow(x)]\npub mod y2022;\n";
+        let text = insert_sorted(lib, "pub mod y", "#[allow(x)]\npub mod y2021;");
+        assert_eq!(text, "pub mod util;\n#[allow(x)]\npub mod y2015;\n#[allow(x)]\npub mod y2021;\n#[allow(x)]\npub mod y2022;\n");
+        let text = insert_sorted(lib, "pub mod y", "#[allow(x)]\npub mod y2023;");
+        assert!(text.ends_with("pub mod y2022;\n#[allow(x)]\npub mod y2023;\n"));
+    }
+
+    #[test]
+    fn test_render() {
+        let day = render(DAY_TEMPLATE, 2023, 4);
+        assert!(day.contains("pub struct Day4;"));
+        assert!(day.contains("        2023\n"));
+        assert!(day.contains("fn parts(&self) -> u32 {\n        0\n"));
+        assert!(render(BIN_TEMPLATE, 2023, 4).contains("input::load(2023, 4)"));
+        assert!(render(MANIFEST_TEMPLATE, 2023, 4).contains("name = \"2023_4\"\npath = \"src/bin/2023_4.rs\"\nrequired-features = [\"bins\"]"));
+    }
+}
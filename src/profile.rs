@@ -0,0 +1,139 @@
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use serde::{Deserialize, Serialize};
+use aoc::config::DEFAULT_PROFILE;
+use crate::client::{Cache, Client};
+use crate::error::AocError;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Profile {
+    pub session: String,
+}
+
+//per user settings, kept out of the project since they hold session tokens
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct UserConfig {
+    #[serde(default)]
+    pub profiles: BTreeMap<String, Profile>,
+}
+
+pub fn config_dir() -> PathBuf {
+    if let Ok(dir) = env::var("AOC_CONFIG_DIR") {
+        return PathBuf::from(dir);
+    }
+    let config_home = env::var("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .unwrap_or_else(|_| PathBuf::from(env::var("HOME").unwrap_or_default()).join(".config"));
+    config_home.join("aoc")
+}
+
+pub fn config_path() -> PathBuf {
+    config_dir().join("config.toml")
+}
+
+impl UserConfig {
+    //no file yet is no profiles yet, a file that can't be read is an error rather than an empty config
+    pub fn load() -> Result<UserConfig, AocError> {
+        let path = config_path();
+        match fs::read_to_string(&path) {
+            Ok(text) => toml::from_str(&text)
+                .map_err(|e| AocError::Io(format!("Could not read {}: {}", path.display(), e))),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(UserConfig::default()),
+            Err(e) => Err(AocError::io("read", &path, e)),
+        }
+    }
+
+    //only the owner may read the tokens, a directory that already existed keeps its permissions
+    pub fn save(&self) -> Result<(), AocError> {
+        let dir = config_dir();
+        let created = !dir.exists();
+        fs::create_dir_all(&dir).map_err(|e| AocError::io("create", &dir, e))?;
+        let mut options = fs::OpenOptions::new();
+        options.write(true).create(true).truncate(true);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::{OpenOptionsExt, PermissionsExt};
+            if created {
+                fs::set_permissions(&dir, fs::Permissions::from_mode(0o700)).map_err(|e| AocError::io("protect", &dir, e))?;
+            }
+            options.mode(0o600);
+        }
+        let path = config_path();
+        let mut file = options.open(&path).map_err(|e| AocError::io("write", &path, e))?;
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            file.set_permissions(fs::Permissions::from_mode(0o600)).map_err(|e| AocError::io("protect", &path, e))?;
+        }
+        file.write_all(toml::to_string(self).unwrap().as_bytes()).map_err(|e| AocError::io("write", &path, e))
+    }
+}
+
+//AOC_COOKIE still works on its own, a named profile always comes from the config
+pub fn session_cookie(profile: &str) -> Result<String, AocError> {
+    if profile == DEFAULT_PROFILE {
+        if let Ok(cookie) = env::var("AOC_COOKIE") {
+            if !cookie.trim().is_empty() {
+                return Ok(cookie.trim().to_string());
+            }
+        }
+    }
+    UserConfig::load()?
+        .profiles
+        .get(profile)
+        .map(|p| p.session.clone())
+        .ok_or(AocError::MissingCookie)
+}
+
+//the name shown in the page header, which is only there when logged in
+fn logged_in_user(page: &str) -> Option<String> {
+    let user = regex::Regex::new(r#"<div class="user">([^<]*)"#).unwrap();
+    user.captures(page).map(|c| c[1].trim().to_string())
+}
+
+pub async fn login(root: &Path, profile: &str, session: Option<String>) -> Result<(), AocError> {
+    let session = match session {
+        Some(session) => session,
+        //kept off the screen and out of the scrollback
+        None => rpassword::prompt_password("Enter your Advent of Code session cookie: ")
+            .map_err(|e| AocError::Io(format!("Could not read the session cookie: {}", e)))?,
+    };
+    let session = session.trim().to_string();
+    if session.is_empty() {
+        return Err(AocError::MissingCookie);
+    }
+    let client = Client::new(root, session.clone())?;
+    let page = client.get("/", Cache::Never).await?;
+    let user = logged_in_user(&page).ok_or(AocError::InvalidCookie)?;
+
+    let mut config = UserConfig::load()?;
+    config.profiles.insert(profile.to_string(), Profile { session });
+    config.save()?;
+    println!("Logged in as {}, saved profile {} to {}", user, profile, config_path().display());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_logged_in_user() {
+        let page = r#"<header><div><h1 class="title-global"><a href="/">Advent of Code</a></h1><div class="user">Ada <span class="star-count">44*</span></div></div></header>"#;
+        assert_eq!(logged_in_user(page), Some("Ada".to_string()));
+        assert_eq!(logged_in_user(r#"<div><a href="/2022/auth/login">[Log In]</a></div>"#), None);
+    }
+
+    #[test]
+    fn test_config_round_trip() {
+        let mut config = UserConfig::default();
+        config.profiles.insert("work".to_string(), Profile { session: "abc".to_string() });
+        let text = toml::to_string(&config).unwrap();
+        assert_eq!(text, "[profiles.work]\nsession = \"abc\"\n");
+        let parsed: UserConfig = toml::from_str(&text).unwrap();
+        assert_eq!(parsed.profiles["work"].session, "abc");
+    }
+}
@@ -1,25 +1,134 @@
-use reqwest;
-use std::env;
-
-async fn fetch_input(year: u32, day: u32, cookie: String) {
-    let client = reqwest::Client::new();
-    let header_map = get_request_headers(cookie);
-    let url = format!("https://adventofcode.com/{}/day/{}/input", year, day);
-    let resp = client.get(url).headers(header_map).send().await.unwrap();
-    let body = resp.text().await.unwrap();
-    print!("{}", body);
-}
-
-pub fn get_request_headers(cookie: String) -> reqwest::header::HeaderMap {
-    let mut headers = reqwest::header::HeaderMap::new();
-    headers.insert(
-        reqwest::header::COOKIE,
-        reqwest::header::HeaderValue::from_str(format!{"session={}", cookie}.as_str()).unwrap(),
-    );
-    headers
-}
-
-pub async fn pull(year: u32, day:u32) {
-    let cookie = env::var("AOC_COOKIE").unwrap();
-    fetch_input(year, day, cookie).await;
+use std::fs;
+use futures::StreamExt;
+use aoc::answer::Answer;
+use aoc::config::Config;
+use crate::answers;
+use crate::client::{Cache, Client};
+use crate::error::AocError;
+use crate::profile::session_cookie;
+use crate::puzzle;
+use crate::run::table;
+use crate::unlock;
+
+//what pulling one file came to, for the summary of a whole year
+enum Pulled {
+    Saved,
+    Skipped,
+}
+
+struct PulledDay {
+    day: u32,
+    input: Result<Pulled, AocError>,
+    puzzle: Result<Pulled, AocError>,
+}
+
+async fn fetch_input(client: &Client, year: u32, day: u32) -> Result<String, AocError> {
+    client.get(&format!("/{}/day/{}/input", year, day), Cache::Forever).await
+}
+
+async fn fetch_puzzle(client: &Client, year: u32, day: u32) -> Result<String, AocError> {
+    client.get(&format!("/{}/day/{}", year, day), Cache::WhenComplete(puzzle::has_part2)).await
+}
+
+async fn pull_input(client: &Client, config: &Config, year: u32, day: u32, force: bool) -> Result<Pulled, AocError> {
+    let input_path = config.input_path(year, day);
+    //inputs never change, only hit the site again when asked to; `aoc new` leaves an empty one to fill
+    let saved = fs::metadata(&input_path).is_ok_and(|m| m.len() > 0);
+    if saved && !force {
+        println!("{} already exists, use --force to download it again", input_path.display());
+        return Ok(Pulled::Skipped);
+    }
+    let input = fetch_input(client, year, day).await?;
+    let input_dir = input_path.parent().unwrap();
+    fs::create_dir_all(input_dir).map_err(|e| AocError::io("create", input_dir, e))?;
+    fs::write(&input_path, input).map_err(|e| AocError::io("write", &input_path, e))?;
+    println!("Saved {}", input_path.display());
+    Ok(Pulled::Saved)
+}
+
+async fn pull_puzzle(client: &Client, config: &Config, year: u32, day: u32, force: bool) -> Result<Pulled, AocError> {
+    let puzzle_path = config.puzzle_path(year, day);
+    //the description only grows once, when part 2 unlocks
+    let saved = fs::read_to_string(&puzzle_path).unwrap_or_default();
+    if puzzle::has_part2(&saved) && !force {
+        println!("{} already has both parts", puzzle_path.display());
+        return Ok(Pulled::Skipped);
+    }
+    let page = fetch_puzzle(client, year, day).await?;
+    puzzle::save(&puzzle_path, &page, &client.base_url)?;
+    //--force is for the input and puzzle, fixtures fixed by hand are only replaced by `aoc examples --force`
+    puzzle::save_examples(&config.root, year, day, &page, false)?;
+    let known: Vec<(u32, Answer)> = puzzle::known_answers(&page).into_iter()
+        .enumerate()
+        .map(|(i, answer)| (i as u32 + 1, Answer::from(answer)))
+        .collect();
+    answers::record(config, year, day, &known)?;
+    Ok(Pulled::Saved)
+}
+
+fn summary_cell(result: &Result<Pulled, AocError>) -> String {
+    match result {
+        Ok(Pulled::Saved) => "saved".to_string(),
+        Ok(Pulled::Skipped) => "already there".to_string(),
+        Err(e) => e.to_string(),
+    }
+}
+
+pub async fn pull(config: &Config, year: u32, day:u32, force: bool, wait: bool) -> Result<(), AocError> {
+    let mut client = Client::new(&config.root, session_cookie(&config.profile)?)?;
+    client.refresh = force;
+    //don't ask the site for a day it will refuse
+    if let Some(remaining) = unlock::time_until_unlock(year, day, chrono::Utc::now()) {
+        if !wait {
+            eprintln!("{} day {} unlocks in {}, use --wait to pull it then", year, day, unlock::format_countdown(remaining));
+            return Err(AocError::NotYetAvailable);
+        }
+        unlock::wait_for_unlock(year, day).await;
+    }
+    pull_input(&client, config, year, day, force).await?;
+    pull_puzzle(&client, config, year, day, force).await?;
+    Ok(())
+}
+
+//every unlocked day of a year, a few at a time; the client still spaces out the requests themselves
+pub async fn pull_all(config: &Config, year: u32, force: bool, concurrency: usize) -> Result<(), AocError> {
+    let mut client = Client::new(&config.root, session_cookie(&config.profile)?)?;
+    client.refresh = force;
+    let now = chrono::Utc::now();
+    let days: Vec<u32> = (1..=25).filter(|day| unlock::time_until_unlock(year, *day, now).is_none()).collect();
+    if days.is_empty() {
+        eprintln!("No day of {} is unlocked yet", year);
+        return Err(AocError::NotYetAvailable);
+    }
+    let client = &client;
+    let mut results: Vec<PulledDay> = futures::stream::iter(days)
+        .map(|day| async move {
+            let input = pull_input(client, config, year, day, force).await;
+            //no point asking for the puzzle when the day isn't there
+            let puzzle = match &input {
+                Err(AocError::NotYetAvailable) => Err(AocError::NotYetAvailable),
+                _ => pull_puzzle(client, config, year, day, force).await,
+            };
+            PulledDay { day, input, puzzle }
+        })
+        .buffer_unordered(concurrency.max(1))
+        .collect()
+        .await;
+    results.sort_by_key(|pulled| pulled.day);
+
+    let mut rows = vec![["day", "input", "puzzle"].map(String::from).to_vec()];
+    rows.extend(results.iter().map(|p| vec![p.day.to_string(), summary_cell(&p.input), summary_cell(&p.puzzle)]));
+    print!("\n{}", table(&rows));
+    //the exit code reports the first failure once everything that could be pulled was
+    match results.into_iter().flat_map(|p| [p.input, p.puzzle]).find_map(|r| r.err()) {
+        Some(e) => Err(e),
+        None => Ok(()),
+    }
+}
+
+pub async fn examples(config: &Config, year: u32, day: u32, force: bool) -> Result<(), AocError> {
+    let mut client = Client::new(&config.root, session_cookie(&config.profile)?)?;
+    client.refresh = force;
+    let page = fetch_puzzle(&client, year, day).await?;
+    puzzle::save_examples(&config.root, year, day, &page, force)
 }
@@ -0,0 +1,217 @@
+use std::fs;
+use std::path::Path;
+use regex::{Captures, Regex};
+use crate::error::AocError;
+
+fn decode_entities(s: &str) -> String {
+    s.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+fn strip_tags(s: &str) -> String {
+    Regex::new(r"<[^>]*>").unwrap().replace_all(s, "").to_string()
+}
+
+//headings, paragraphs, lists, links and emphasis outside of <pre> blocks
+fn convert_inline(html: &str, base_url: &str) -> String {
+    let replacements = [
+        (r"(?s)<h2[^>]*>(.*?)</h2>", "\n## $1\n\n"),
+        (r"(?s)<em[^>]*>(.*?)</em>", "*$1*"),
+        (r"(?s)<li>", "- "),
+        (r"(?s)</li>\s*", "\n"),
+        (r"(?s)</?ul>\s*", "\n"),
+        (r"(?s)</p>", "\n\n"),
+    ];
+    let code = Regex::new(r"(?s)<code>(.*?)</code>").unwrap();
+    let mut s = code.replace_all(html, |c: &Captures| format!("`{}`", strip_tags(&c[1]))).to_string();
+    let link = Regex::new(r#"(?s)<a href="([^"]*)"[^>]*>(.*?)</a>"#).unwrap();
+    s = link.replace_all(&s, |c: &Captures| {
+        let href = if c[1].starts_with('/') { format!("{}{}", base_url, &c[1]) } else { c[1].to_string() };
+        format!("[{}]({})", &c[2], href)
+    }).to_string();
+    for (pattern, replacement) in replacements {
+        s = Regex::new(pattern).unwrap().replace_all(&s, replacement).to_string();
+    }
+    decode_entities(&strip_tags(&s))
+}
+
+fn article_to_markdown(html: &str, base_url: &str) -> String {
+    let pre = Regex::new(r"(?s)<pre><code>(.*?)</code></pre>").unwrap();
+    let mut s = String::new();
+    let mut last = 0;
+    for c in pre.captures_iter(html) {
+        let block = c.get(0).unwrap();
+        s.push_str(&convert_inline(&html[last..block.start()], base_url));
+        s.push_str(&format!("\n```\n{}```\n\n", decode_entities(&strip_tags(&c[1]))));
+        last = block.end();
+    }
+    s.push_str(&convert_inline(&html[last..], base_url));
+    let blank_lines = Regex::new(r"\n{3,}").unwrap();
+    blank_lines.replace_all(s.trim(), "\n\n").to_string()
+}
+
+//every <article> on the puzzle page, part 1 then part 2 once it is unlocked
+pub fn to_markdown(page: &str, base_url: &str) -> String {
+    let article = Regex::new(r"(?s)<article[^>]*>(.*?)</article>").unwrap();
+    let parts: Vec<String> = article
+        .captures_iter(page)
+        .map(|c| article_to_markdown(&c[1], base_url))
+        .collect();
+    parts.join("\n\n") + "\n"
+}
+
+pub fn has_part2(markdown: &str) -> bool {
+    markdown.contains("--- Part Two ---")
+}
+
+//write the puzzle unless the saved copy already has as much of it
+pub fn save(puzzle_path: &Path, page: &str, base_url: &str) -> Result<(), AocError> {
+    let markdown = to_markdown(page, base_url);
+    let saved = fs::read_to_string(puzzle_path).unwrap_or_default();
+    if puzzle_path.exists() && has_part2(&saved) == has_part2(&markdown) {
+        println!("{} is up to date", puzzle_path.display());
+        return Ok(());
+    }
+    let puzzle_dir = puzzle_path.parent().unwrap();
+    fs::create_dir_all(puzzle_dir).map_err(|e| AocError::io("create", puzzle_dir, e))?;
+    fs::write(puzzle_path, &markdown).map_err(|e| AocError::io("write", puzzle_path, e))?;
+    if !saved.is_empty() {
+        println!("Added part 2 to {}", puzzle_path.display());
+    } else {
+        println!("Saved {}", puzzle_path.display());
+    }
+    Ok(())
+}
+
+//once a part is solved its page shows the accepted answer under the article
+pub fn known_answers(page: &str) -> Vec<String> {
+    let answer = Regex::new(r"(?s)Your puzzle answer was <code>(.*?)</code>").unwrap();
+    answer.captures_iter(page).map(|c| decode_entities(&strip_tags(&c[1]))).collect()
+}
+
+pub struct Example {
+    pub part: u32,
+    pub input: String,
+    pub answer: String,
+}
+
+//each part's example is the first <pre><code> block of its article, part 2 reuses part 1's when it has none,
+//and the expected answer is the last emphasized code in the article
+pub fn examples(page: &str) -> Vec<Example> {
+    let article = Regex::new(r"(?s)<article[^>]*>(.*?)</article>").unwrap();
+    let pre = Regex::new(r"(?s)<pre><code>(.*?)</code></pre>").unwrap();
+    let answer = Regex::new(r"(?s)<code><em>(.*?)</em></code>").unwrap();
+    let mut examples: Vec<Example> = Vec::new();
+    let mut input: Option<String> = None;
+    for (i, c) in article.captures_iter(page).enumerate() {
+        if let Some(block) = pre.captures(&c[1]) {
+            input = Some(decode_entities(&strip_tags(&block[1])));
+        }
+        let expected = answer.captures_iter(&c[1]).last().map(|a| decode_entities(&strip_tags(&a[1])));
+        if let (Some(input), Some(answer)) = (&input, expected) {
+            examples.push(Example { part: i as u32 + 1, input: input.clone(), answer });
+        }
+    }
+    examples
+}
+
+//fixtures are edited by hand when the guess is wrong, so only replace them when forced
+pub fn save_examples(root: &Path, year: u32, day: u32, page: &str, force: bool) -> Result<(), AocError> {
+    let fixture_dir = root.join(format!("fixtures/{}_{}", year, day));
+    for example in examples(page) {
+        let input_path = fixture_dir.join(format!("part{}.txt", example.part));
+        let answer_path = fixture_dir.join(format!("part{}.answer", example.part));
+        if answer_path.exists() && !force {
+            println!("{} already exists", answer_path.display());
+            continue;
+        }
+        //a day without examples gets no directory, `aoc list` counts one as tests
+        fs::create_dir_all(&fixture_dir).map_err(|e| AocError::io("create", &fixture_dir, e))?;
+        fs::write(&input_path, &example.input).map_err(|e| AocError::io("write", &input_path, e))?;
+        fs::write(&answer_path, format!("{}\n", example.answer)).map_err(|e| AocError::io("write", &answer_path, e))?;
+        println!("Saved example for part {} to {}", example.part, input_path.display());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_input() -> &'static str {
+r#"<main>
+<article class="day-desc"><h2>--- Day 1: Calorie Counting ---</h2><p>The jungle must be too overgrown and difficult to navigate in vehicles or access from the air; the Elves' expedition traditionally goes on foot.</p>
+<p>For example, suppose the Elves finish writing their items' <em>Calories</em> into a list (see <a href="/2022/day/1/input" target="_blank">your input</a>):</p>
+<pre><code>1000
+2000
+
+&lt;3000&gt;
+</code></pre>
+<ul>
+<li>The first Elf is carrying <code>6000</code> Calories.</li>
+<li>In case the Elves get hungry, find <em>the Elf carrying the most</em>.</li>
+</ul>
+<p>In the example above, this is <code><em>24000</em></code>. How many total <em>Calories</em> is that Elf carrying?</p>
+</article>
+<p>Your puzzle answer was <code>67450</code>.</p><article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>Find the top <em>three</em> Elves &amp; their <code>total</code>.</p>
+<p>In the example above, the top three Elves are carrying <code><em>45000</em></code> Calories.</p>
+</article>
+</main>"#
+    }
+
+    #[test]
+    fn test_to_markdown() {
+        assert_eq!(to_markdown(test_input(), "https://adventofcode.com"),
+"## --- Day 1: Calorie Counting ---
+
+The jungle must be too overgrown and difficult to navigate in vehicles or access from the air; the Elves' expedition traditionally goes on foot.
+
+For example, suppose the Elves finish writing their items' *Calories* into a list (see [your input](https://adventofcode.com/2022/day/1/input)):
+
+```
+1000
+2000
+
+<3000>
+```
+
+- The first Elf is carrying `6000` Calories.
+- In case the Elves get hungry, find *the Elf carrying the most*.
+
+In the example above, this is `24000`. How many total *Calories* is that Elf carrying?
+
+## --- Part Two ---
+
+Find the top *three* Elves & their `total`.
+
+In the example above, the top three Elves are carrying `45000` Calories.
+");
+    }
+
+    #[test]
+    fn test_examples() {
+        let examples = examples(test_input());
+        assert_eq!(examples.len(), 2);
+        assert_eq!((examples[0].part, examples[0].input.as_str(), examples[0].answer.as_str()), (1, "1000\n2000\n\n<3000>\n", "24000"));
+        assert_eq!((examples[1].part, examples[1].input.as_str(), examples[1].answer.as_str()), (2, "1000\n2000\n\n<3000>\n", "45000"));
+        //a part without an emphasized answer has no example rather than a wrong one
+        let part1 = test_input().split("<p>Your puzzle answer").next().unwrap();
+        assert!(super::examples(&part1.replace("<code><em>24000</em></code>", "<code>24000</code>")).is_empty());
+    }
+
+    #[test]
+    fn test_known_answers() {
+        assert_eq!(known_answers(test_input()), vec!["67450"]);
+        assert!(known_answers("<p>Answer: <input type=\"text\" name=\"answer\"/></p>").is_empty());
+    }
+
+    #[test]
+    fn test_has_part2() {
+        let part1 = test_input().split("<p>Your puzzle answer").next().unwrap();
+        assert!(!has_part2(&to_markdown(part1, "")));
+        assert!(has_part2(&to_markdown(test_input(), "")));
+    }
+}
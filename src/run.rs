@@ -0,0 +1,272 @@
+use std::any::Any;
+use std::time::{Duration, Instant};
+use aoc::answer::Answer;
+use aoc::config::{Config, Format};
+use aoc::input::{Input, Source};
+use aoc::solution::{self, DynSolution};
+use serde_json::json;
+use crate::error::AocError;
+
+struct Outcome {
+    //None for days that don't parse their input
+    parse_time: Option<Duration>,
+    answers: Vec<(Answer, Duration)>,
+    //alternative implementations that gave a different answer than their part
+    disagreements: Vec<String>,
+}
+
+fn solve(solution: &dyn DynSolution, input: &str) -> Outcome {
+    let time = Instant::now();
+    let parsed = solution.parse(input);
+    let parse_time = solution.parses().then(|| time.elapsed());
+    let answers: Vec<(Answer, Duration)> = (1..=solution.parts())
+        .map_while(|part| {
+            let time = Instant::now();
+            let answer = solution.part(part, parsed.as_ref())?;
+            Some((answer, time.elapsed()))
+        })
+        .collect();
+    let disagreements = disagreements(solution, parsed.as_ref(), &answers);
+    Outcome { parse_time, answers, disagreements }
+}
+
+fn disagreements(solution: &dyn DynSolution, parsed: &dyn Any, answers: &[(Answer, Duration)]) -> Vec<String> {
+    solution.alternatives()
+        .into_iter()
+        .filter_map(|(part, name)| {
+            let (expected, _) = answers.get(part as usize - 1)?;
+            let answer = solution.alternative(part, name, parsed)?;
+            (answer != *expected).then(|| format!(
+                "{} day {} part {}: {} gave {}, not {}", solution.year(), solution.day(), part, name, answer, expected
+            ))
+        })
+        .collect()
+}
+
+fn report_disagreements(outcome: &Outcome) {
+    for disagreement in &outcome.disagreements {
+        eprintln!("Alternatives disagree, {}", disagreement);
+    }
+}
+
+fn read_input(config: &Config, year: u32, day: u32) -> Option<String> {
+    Input::new(year, day, Source::Config).read(config).ok()
+}
+
+fn format_time(time: Duration) -> String {
+    format!("{:.1}ms", time.as_secs_f64() * 1000.0)
+}
+
+//answers spanning several lines (like a drawn grid) don't fit in a table cell
+pub fn table_answer(answer: &Answer) -> String {
+    match answer {
+        Answer::Grid(rows) => format!("<{} lines>", rows.len()),
+        _ => answer.to_string(),
+    }
+}
+
+pub fn table(rows: &[Vec<String>]) -> String {
+    let columns = rows.iter().map(|row| row.len()).max().unwrap_or(0);
+    let widths: Vec<usize> = (0..columns)
+        .map(|i| rows.iter().filter_map(|row| row.get(i)).map(|cell| cell.chars().count()).max().unwrap_or(0))
+        .collect();
+    let mut s = String::new();
+    for row in rows {
+        let line = row.iter()
+            .enumerate()
+            .map(|(i, cell)| format!("{:<width$}", cell, width = widths[i]))
+            .collect::<Vec<_>>()
+            .join("  ");
+        s.push_str(line.trim_end());
+        s.push('\n');
+    }
+    s
+}
+
+fn load(config: &Config, input: &Input) -> Result<(&'static dyn DynSolution, String), AocError> {
+    let (year, day) = (input.year, input.day);
+    let solution = solution::find(year, day).ok_or_else(|| AocError::NoSolution(format!("{} day {}", year, day)))?;
+    let text = input.read(config)
+        .map_err(|e| AocError::Input(format!("Could not read input {}: {}", input.describe(config), e)))?;
+    Ok((solution, text))
+}
+
+//answer a single part, for commands that need the answer rather than printing it
+pub fn answer(config: &Config, year: u32, day: u32, part: u32) -> Result<Answer, AocError> {
+    let (solution, input) = load(config, &Input::new(year, day, Source::Config))?;
+    let unsolved = || AocError::NoSolution(format!("{} day {} part {}", year, day, part));
+    if part == 0 || part > solution.parts() {
+        return Err(unsolved());
+    }
+    solution.part(part, solution.parse(&input).as_ref()).ok_or_else(unsolved)
+}
+
+//every part's answer, for checking them rather than printing them
+pub fn answers(config: &Config, input: &Input) -> Result<Vec<Answer>, AocError> {
+    let (solution, input) = load(config, input)?;
+    Ok(solve(solution, &input).answers.into_iter().map(|(answer, _)| answer).collect())
+}
+
+//the same results as the table, for scripts
+fn outcome_json(solution: &dyn DynSolution, outcome: Option<&Outcome>) -> serde_json::Value {
+    match outcome {
+        Some(outcome) => json!({
+            "year": solution.year(),
+            "day": solution.day(),
+            "parse_ms": outcome.parse_time.map(|time| time.as_secs_f64() * 1000.0),
+            "parts": outcome.answers.iter().enumerate()
+                .map(|(i, (answer, time))| json!({ "part": i + 1, "answer": answer, "ms": time.as_secs_f64() * 1000.0 }))
+                .collect::<Vec<_>>(),
+            "disagreements": outcome.disagreements,
+        }),
+        None => json!({ "year": solution.year(), "day": solution.day(), "error": "no input" }),
+    }
+}
+
+pub fn run(config: &Config, input: &Input) -> Result<(), AocError> {
+    let (solution, input) = load(config, input)?;
+    let outcome = solve(solution, &input);
+    report_disagreements(&outcome);
+    if config.format == Format::Json {
+        println!("{}", outcome_json(solution, Some(&outcome)));
+        return Ok(());
+    }
+    for (i, (answer, _)) in outcome.answers.iter().enumerate() {
+        //multi-line answers (like a drawn grid) start on their own line
+        if answer.is_grid() {
+            println!("Part {}:\n{}", i + 1, answer);
+        } else {
+            println!("Part {}: {}", i + 1, answer);
+        }
+    }
+    Ok(())
+}
+
+//run every registered day, or every day of one year, and print a summary table
+pub fn run_all(config: &Config, year: Option<u32>) -> Result<(), AocError> {
+    let solutions: Vec<&dyn DynSolution> = solution::all()
+        .into_iter()
+        .filter(|s| year.is_none() || year == Some(s.year()))
+        .collect();
+    if solutions.is_empty() {
+        return Err(AocError::NoSolution(match year {
+            Some(year) => year.to_string(),
+            None => "any day".to_string(),
+        }));
+    }
+    if config.format == Format::Json {
+        let results: Vec<serde_json::Value> = solutions.iter()
+            .map(|solution| {
+                let outcome = read_input(config, solution.year(), solution.day()).map(|input| solve(*solution, &input));
+                outcome.iter().for_each(report_disagreements);
+                outcome_json(*solution, outcome.as_ref())
+            })
+            .collect();
+        println!("{}", serde_json::Value::Array(results));
+        return Ok(());
+    }
+    let mut rows = vec![["year", "day", "parse", "part 1", "time", "part 2", "time"].map(String::from).to_vec()];
+    let mut total = Duration::ZERO;
+    for solution in solutions {
+        let mut row = vec![solution.year().to_string(), solution.day().to_string()];
+        match read_input(config, solution.year(), solution.day()) {
+            Some(input) => {
+                let outcome = solve(solution, &input);
+                report_disagreements(&outcome);
+                total += outcome.parse_time.unwrap_or_default();
+                row.push(outcome.parse_time.map(format_time).unwrap_or_else(|| "-".to_string()));
+                for (answer, time) in outcome.answers {
+                    total += time;
+                    row.push(table_answer(&answer));
+                    row.push(format_time(time));
+                }
+            }
+            None => row.push("no input".to_string()),
+        }
+        rows.push(row);
+    }
+    print!("{}", table(&rows));
+    println!("Total: {}", format_time(total));
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc::solution::{Alternative, Solution};
+
+    #[test]
+    fn test_table() {
+        let rows = vec![
+            vec!["year".to_string(), "day".to_string(), "part 1".to_string()],
+            vec!["2022".to_string(), "10".to_string(), table_answer(&Answer::from(12460))],
+            vec!["2022".to_string(), "11".to_string()],
+        ];
+        assert_eq!(table(&rows), "year  day  part 1\n2022  10   12460\n2022  11\n");
+    }
+
+    struct Doubler;
+
+    impl Solution for Doubler {
+        type Input = u32;
+
+        fn year(&self) -> u32 {
+            2022
+        }
+
+        fn day(&self) -> u32 {
+            1
+        }
+
+        fn parse(&self, input: &str) -> u32 {
+            input.trim().parse().unwrap()
+        }
+
+        fn part1(&self, input: &u32) -> Answer {
+            (input * 2).into()
+        }
+
+        fn part2(&self, input: &u32) -> Option<Answer> {
+            Some((input * 4).into())
+        }
+
+        fn alternatives(&self) -> Vec<Alternative<u32>> {
+            vec![
+                Alternative { part: 1, name: "added", solve: |input| (input + input).into() },
+                Alternative { part: 2, name: "off by one", solve: |input| (input * 4 + 1).into() },
+            ]
+        }
+    }
+
+    #[test]
+    fn test_alternatives_are_cross_checked() {
+        let outcome = solve(&Doubler, "5\n");
+        assert_eq!(outcome.answers.iter().map(|(a, _)| a.to_string()).collect::<Vec<_>>(), vec!["10", "20"]);
+        assert_eq!(outcome.disagreements, vec!["2022 day 1 part 2: off by one gave 21, not 20"]);
+        assert!(solve(solution::find(2022, 1).unwrap(), "1\n\n2\n\n3\n").disagreements.is_empty());
+    }
+
+    #[test]
+    fn test_parse_is_only_timed_when_there_is_parsing() {
+        assert!(solve(&Doubler, "5\n").parse_time.is_some());
+        //2022 day 1 keeps the raw text
+        assert!(solve(solution::find(2022, 1).unwrap(), "1\n\n2\n\n3\n").parse_time.is_none());
+    }
+
+    #[test]
+    fn test_part_numbers() {
+        assert_eq!(DynSolution::part(&Doubler, 1, &5u32), Some(Answer::from(10)));
+        assert_eq!(DynSolution::part(&Doubler, 2, &5u32), Some(Answer::from(20)));
+        assert_eq!(DynSolution::part(&Doubler, 0, &5u32), None);
+        assert_eq!(DynSolution::part(&Doubler, 3, &5u32), None);
+        assert_eq!(DynSolution::alternative(&Doubler, 1, "added", &5u32), Some(Answer::from(10)));
+        assert_eq!(DynSolution::alternative(&Doubler, 2, "added", &5u32), None);
+        assert!(Doubler.alternative_solver(1, "missing").is_none());
+    }
+
+    #[test]
+    fn test_table_answer() {
+        assert_eq!(table_answer(&Answer::from("#..#\n#..#")), "<2 lines>");
+        assert_eq!(table_answer(&Answer::from("NTWZZWHFV")), "NTWZZWHFV");
+    }
+}
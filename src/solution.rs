@@ -0,0 +1,161 @@
+use std::any::Any;
+use crate::answer::Answer;
+
+//another way of answering a part, checked against the main one and compared with it in `aoc bench`
+pub struct Alternative<I> {
+    pub part: u32,
+    pub name: &'static str,
+    pub solve: fn(&I) -> Answer,
+}
+
+//a day's puzzle: how to parse the input once, and how to answer each part from it
+pub trait Solution {
+    type Input: 'static;
+
+    fn year(&self) -> u32;
+    fn day(&self) -> u32;
+
+    //number of parts solved so far, days that only solve part 1 override this
+    fn parts(&self) -> u32 {
+        2
+    }
+
+    fn parse(&self, input: &str) -> Self::Input;
+
+    //days that keep the raw text as their input override this, their parse is only a copy not worth timing
+    fn parses(&self) -> bool {
+        true
+    }
+
+    fn part1(&self, input: &Self::Input) -> Answer;
+
+    //None until part 2 is solved
+    fn part2(&self, _input: &Self::Input) -> Option<Answer> {
+        None
+    }
+
+    //named alternative implementations of either part, most days have none
+    fn alternatives(&self) -> Vec<Alternative<Self::Input>> {
+        Vec::new()
+    }
+}
+
+//an alternative of any day, taking the input its day parsed
+pub type AlternativeSolver = Box<dyn Fn(&dyn Any) -> Answer>;
+
+//object safe view of a Solution, so days with different input types can share a registry
+pub trait DynSolution {
+    fn year(&self) -> u32;
+    fn day(&self) -> u32;
+    fn parts(&self) -> u32;
+    fn parse(&self, input: &str) -> Box<dyn Any>;
+    fn parses(&self) -> bool;
+    fn part1(&self, input: &dyn Any) -> Answer;
+    fn part2(&self, input: &dyn Any) -> Option<Answer>;
+    //the part and name of each alternative, in registration order
+    fn alternatives(&self) -> Vec<(u32, &'static str)>;
+    //looked up once, so timing it in a loop times only the alternative
+    fn alternative_solver(&self, part: u32, name: &str) -> Option<AlternativeSolver>;
+
+    //None when the day has no such alternative
+    fn alternative(&self, part: u32, name: &str, input: &dyn Any) -> Option<Answer> {
+        self.alternative_solver(part, name).map(|solve| solve(input))
+    }
+
+    //None for a part that doesn't exist or isn't solved yet
+    fn part(&self, part: u32, input: &dyn Any) -> Option<Answer> {
+        match part {
+            1 => Some(self.part1(input)),
+            2 => self.part2(input),
+            _ => None,
+        }
+    }
+}
+
+impl<S: Solution> DynSolution for S {
+    fn year(&self) -> u32 {
+        Solution::year(self)
+    }
+
+    fn day(&self) -> u32 {
+        Solution::day(self)
+    }
+
+    fn parts(&self) -> u32 {
+        Solution::parts(self)
+    }
+
+    fn parse(&self, input: &str) -> Box<dyn Any> {
+        Box::new(Solution::parse(self, input))
+    }
+
+    fn parses(&self) -> bool {
+        Solution::parses(self)
+    }
+
+    fn part1(&self, input: &dyn Any) -> Answer {
+        Solution::part1(self, input.downcast_ref().expect("input was not parsed by this solution"))
+    }
+
+    fn part2(&self, input: &dyn Any) -> Option<Answer> {
+        Solution::part2(self, input.downcast_ref().expect("input was not parsed by this solution"))
+    }
+
+    fn alternatives(&self) -> Vec<(u32, &'static str)> {
+        Solution::alternatives(self).iter().map(|a| (a.part, a.name)).collect()
+    }
+
+    fn alternative_solver(&self, part: u32, name: &str) -> Option<AlternativeSolver> {
+        let solve = Solution::alternatives(self)
+            .into_iter()
+            .find(|a| a.part == part && a.name == name)?
+            .solve;
+        Some(Box::new(move |input: &dyn Any| solve(input.downcast_ref().expect("input was not parsed by this solution"))))
+    }
+}
+
+//every registered solution, ordered by year then day
+pub fn all() -> Vec<&'static dyn DynSolution> {
+    let mut solutions = Vec::new();
+    solutions.extend(crate::y2015::solutions());
+    solutions.extend(crate::y2021::solutions());
+    solutions.extend(crate::y2022::solutions());
+    solutions
+}
+
+pub fn find(year: u32, day: u32) -> Option<&'static dyn DynSolution> {
+    all().into_iter().find(|s| s.year() == year && s.day() == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn registry_is_sorted_and_unique() {
+        let keys: Vec<(u32, u32)> = all().iter().map(|s| (s.year(), s.day())).collect();
+        let mut sorted = keys.clone();
+        sorted.sort();
+        sorted.dedup();
+        assert_eq!(keys, sorted);
+    }
+
+    #[test]
+    fn find_returns_matching_day() {
+        let solution = find(2022, 12).unwrap();
+        assert_eq!((solution.year(), solution.day()), (2022, 12));
+        assert!(find(2022, 26).is_none());
+    }
+
+    #[test]
+    fn alternatives_are_unique_per_part() {
+        for solution in all() {
+            let mut alternatives = solution.alternatives();
+            assert!(alternatives.iter().all(|(part, _)| (1..=solution.parts()).contains(part)));
+            let count = alternatives.len();
+            alternatives.sort();
+            alternatives.dedup();
+            assert_eq!(alternatives.len(), count, "{} day {}", solution.year(), solution.day());
+        }
+    }
+}
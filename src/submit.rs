@@ -0,0 +1,147 @@
+use std::fmt::Display;
+use std::time::Duration;
+use regex::Regex;
+use aoc::answer::Answer;
+use aoc::config::Config;
+use crate::answers;
+use crate::error::AocError;
+use crate::ledger::Ledger;
+use crate::profile::session_cookie;
+use crate::client::Client;
+use crate::run;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    RateLimited(Duration),
+    AlreadySolved,
+    Unknown(String),
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "Correct!"),
+            Verdict::TooHigh => write!(f, "Wrong, your answer is too high"),
+            Verdict::TooLow => write!(f, "Wrong, your answer is too low"),
+            Verdict::Wrong => write!(f, "Wrong answer"),
+            Verdict::RateLimited(wait) => write!(f, "Answered too recently, wait {}s before trying again", wait.as_secs()),
+            Verdict::AlreadySolved => write!(f, "This part is already solved"),
+            Verdict::Unknown(text) => write!(f, "Unrecognised response: {}", text),
+        }
+    }
+}
+
+//the verdict is the text of the page's <article>
+fn article_text(body: &str) -> String {
+    let article = Regex::new(r"(?s)<article>(.*?)</article>").unwrap();
+    let tags = Regex::new(r"<[^>]*>").unwrap();
+    let inner = article.captures(body).map(|c| c[1].to_string()).unwrap_or_else(|| body.to_string());
+    tags.replace_all(&inner, "").split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+pub fn parse_verdict(body: &str) -> Verdict {
+    let text = article_text(body);
+    if text.contains("That's the right answer") {
+        Verdict::Correct
+    } else if text.contains("your answer is too high") {
+        Verdict::TooHigh
+    } else if text.contains("your answer is too low") {
+        Verdict::TooLow
+    } else if text.contains("That's not the right answer") {
+        Verdict::Wrong
+    } else if text.contains("You gave an answer too recently") {
+        let wait = Regex::new(r"You have (?:(\d+)m )?(\d+)s left to wait").unwrap();
+        let seconds = wait.captures(&text)
+            .map(|c| {
+                let minutes = c.get(1).map(|m| m.as_str().parse::<u64>().unwrap()).unwrap_or(0);
+                minutes * 60 + c[2].parse::<u64>().unwrap()
+            })
+            .unwrap_or(0);
+        Verdict::RateLimited(Duration::from_secs(seconds))
+    } else if text.contains("You don't seem to be solving the right level") {
+        Verdict::AlreadySolved
+    } else {
+        Verdict::Unknown(text)
+    }
+}
+
+async fn post_answer(client: &Client, year: u32, day: u32, part: u32, answer: &str) -> Result<Verdict, AocError> {
+    let params = [("level", part.to_string()), ("answer", answer.to_string())];
+    let body = client.post(&format!("/{}/day/{}/answer", year, day), &params).await?;
+    Ok(parse_verdict(&body))
+}
+
+pub async fn submit(config: &Config, year: u32, day: u32, part: u32, answer: Option<String>) -> Result<(), AocError> {
+    let profile = config.profile.as_str();
+    let client = Client::new(&config.root, session_cookie(profile)?)?;
+    let answer = match answer {
+        Some(answer) => Answer::from(answer),
+        None => run::answer(config, year, day, part)?,
+    };
+    //grids have to be read and typed in by hand
+    if answer.is_grid() {
+        return Err(AocError::Refused(format!("the answer spans several lines, pass it to submit explicitly:\n{}", answer)));
+    }
+    //a repeat of a wrong answer would only earn another cooldown
+    let mut ledger = Ledger::load(&config.root)?;
+    if let Some(reason) = ledger.check(profile, year, day, part, &answer) {
+        return Err(AocError::Refused(reason));
+    }
+    println!("Submitting {} for {} day {} part {}", answer, year, day, part);
+    let verdict = post_answer(&client, year, day, part, &answer.to_string()).await?;
+    ledger.record(profile, year, day, part, &answer, &verdict);
+    ledger.save(&config.root)?;
+    //anything but a correct answer fails, so scripts can tell them apart
+    if verdict != Verdict::Correct {
+        return Err(AocError::NotAccepted(verdict.to_string()));
+    }
+    println!("{}", verdict);
+    answers::record(config, year, day, &[(part, answer)])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn page(article: &str) -> String {
+        format!("<html><body><main>\n<article><p>{}</p></article>\n</main></body></html>", article)
+    }
+
+    #[test]
+    fn test_correct() {
+        let body = page("That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer to collecting enough star fruit. <a href=\"/2022/day/1#part2\">[Continue to Part Two]</a>");
+        assert_eq!(parse_verdict(&body), Verdict::Correct);
+    }
+
+    #[test]
+    fn test_too_high_and_low() {
+        let high = page("That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data; please wait one minute before trying again. <a href=\"/2022/day/1\">[Return to Day 1]</a>");
+        let low = page("That's not the right answer; your answer is too low.  please wait one minute before trying again.");
+        assert_eq!(parse_verdict(&high), Verdict::TooHigh);
+        assert_eq!(parse_verdict(&low), Verdict::TooLow);
+    }
+
+    #[test]
+    fn test_wrong() {
+        let body = page("That's not the right answer.  If you're stuck, make sure you're using the full input data.");
+        assert_eq!(parse_verdict(&body), Verdict::Wrong);
+    }
+
+    #[test]
+    fn test_rate_limited() {
+        let seconds = page("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 37s left to wait.");
+        let minutes = page("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 4m 12s left to wait.");
+        assert_eq!(parse_verdict(&seconds), Verdict::RateLimited(Duration::from_secs(37)));
+        assert_eq!(parse_verdict(&minutes), Verdict::RateLimited(Duration::from_secs(252)));
+    }
+
+    #[test]
+    fn test_already_solved() {
+        let body = page("You don't seem to be solving the right level.  Did you already complete it?");
+        assert_eq!(parse_verdict(&body), Verdict::AlreadySolved);
+    }
+}
@@ -0,0 +1,72 @@
+use std::io::Write;
+use std::time::Duration;
+use chrono::{DateTime, Datelike, TimeZone, Utc};
+use rand::Rng;
+
+//puzzles unlock at midnight US Eastern, which in December is always 05:00 UTC
+pub fn unlock_time(year: u32, day: u32) -> Option<DateTime<Utc>> {
+    Utc.with_ymd_and_hms(year as i32, 12, day, 5, 0, 0).single()
+}
+
+//how long until the day unlocks, None once it has (or if it isn't a real date, the site will say so)
+pub fn time_until_unlock(year: u32, day: u32, now: DateTime<Utc>) -> Option<Duration> {
+    (unlock_time(year, day)? - now).to_std().ok().filter(|d| !d.is_zero())
+}
+
+//the most recent event that has started, puzzles from December on
+pub fn latest_event(now: DateTime<Utc>) -> u32 {
+    if now.month() == 12 { now.year() as u32 } else { now.year() as u32 - 1 }
+}
+
+pub fn format_countdown(remaining: Duration) -> String {
+    let seconds = remaining.as_secs();
+    let (days, hours, minutes, seconds) = (seconds / 86400, seconds / 3600 % 24, seconds / 60 % 60, seconds % 60);
+    if days > 0 {
+        format!("{}d {}h {}m {}s", days, hours, minutes, seconds)
+    } else {
+        format!("{}h {:02}m {:02}s", hours, minutes, seconds)
+    }
+}
+
+//sleep until the day unlocks, ticking a countdown, then a few more seconds so not everyone hits the site at once
+pub async fn wait_for_unlock(year: u32, day: u32) {
+    while let Some(remaining) = time_until_unlock(year, day, Utc::now()) {
+        eprint!("\r{} day {} unlocks in {}   ", year, day, format_countdown(remaining));
+        std::io::stderr().flush().unwrap();
+        tokio::time::sleep(remaining.min(Duration::from_secs(1))).await;
+    }
+    let delay = rand::thread_rng().gen_range(2..=10);
+    eprintln!("\r{} day {} is unlocked, pulling in {}s   ", year, day, delay);
+    tokio::time::sleep(Duration::from_secs(delay)).await;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unlock_time() {
+        assert_eq!(unlock_time(2022, 1).unwrap().to_rfc3339(), "2022-12-01T05:00:00+00:00");
+        assert_eq!(unlock_time(2022, 32), None);
+    }
+
+    #[test]
+    fn test_time_until_unlock() {
+        let before = Utc.with_ymd_and_hms(2022, 12, 1, 4, 59, 30).unwrap();
+        let after = Utc.with_ymd_and_hms(2022, 12, 1, 5, 0, 0).unwrap();
+        assert_eq!(time_until_unlock(2022, 1, before), Some(Duration::from_secs(30)));
+        assert_eq!(time_until_unlock(2022, 1, after), None);
+    }
+
+    #[test]
+    fn test_latest_event() {
+        assert_eq!(latest_event(Utc.with_ymd_and_hms(2022, 12, 1, 0, 0, 0).unwrap()), 2022);
+        assert_eq!(latest_event(Utc.with_ymd_and_hms(2023, 11, 30, 0, 0, 0).unwrap()), 2022);
+    }
+
+    #[test]
+    fn test_format_countdown() {
+        assert_eq!(format_countdown(Duration::from_secs(3 * 3600 + 5 * 60 + 9)), "3h 05m 09s");
+        assert_eq!(format_countdown(Duration::from_secs(2 * 86400 + 61)), "2d 0h 1m 1s");
+    }
+}
@@ -0,0 +1,107 @@
+use std::str::FromStr;
+use ndarray::Array2;
+
+//a point on a 2d map, days that index arrays use usize, days on an unbounded plane a signed type
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Position<T = i64> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Position<T> {
+    pub fn new(x: T, y: T) -> Position<T> {
+        Position { x, y }
+    }
+}
+
+//reads x,y, the way most puzzles write coordinates
+impl<T: FromStr> FromStr for Position<T> {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (x, y) = s.trim().split_once(',').ok_or(())?;
+        Ok(Position::new(x.trim().parse().map_err(|_| ())?, y.trim().parse().map_err(|_| ())?))
+    }
+}
+
+macro_rules! distances {
+    ($($t:ty),*) => {
+        $(impl Position<$t> {
+            //steps between two positions moving only up, down, left or right
+            pub fn manhattan_distance(&self, other: &Self) -> i64 {
+                (self.x.abs_diff(other.x) + self.y.abs_diff(other.y)) as i64
+            }
+
+            //steps between two positions when diagonal moves are allowed too, 1 for touching positions
+            pub fn chebyshev_distance(&self, other: &Self) -> i64 {
+                self.x.abs_diff(other.x).max(self.y.abs_diff(other.y)) as i64
+            }
+        })*
+    };
+}
+
+distances!(i32, i64, usize);
+
+//a map drawn in characters, one row per line, indexed [[row, column]]
+pub fn grid(input: &str) -> Array2<u8> {
+    let rows: Vec<&[u8]> = input.lines().filter(|line| !line.is_empty()).map(str::as_bytes).collect();
+    let columns = rows.first().map_or(0, |row| row.len());
+    Array2::from_shape_vec((rows.len(), columns), rows.concat()).expect("grid rows have different lengths")
+}
+
+//the positions above, left, below and right of position that are inside a grid of dim
+pub fn neighbours(position: &Position<usize>, dim: (usize, usize)) -> Vec<Position<usize>> {
+    let Position { x, y } = *position;
+    let mut neighbours = Vec::with_capacity(4);
+    if x > 0 {
+        neighbours.push(Position::new(x - 1, y));
+    }
+    if y > 0 {
+        neighbours.push(Position::new(x, y - 1));
+    }
+    if x + 1 < dim.0 {
+        neighbours.push(Position::new(x + 1, y));
+    }
+    if y + 1 < dim.1 {
+        neighbours.push(Position::new(x, y + 1));
+    }
+    neighbours
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_distances() {
+        let a: Position = Position::new(-2, 3);
+        let b = Position::new(4, 1);
+        assert_eq!(a.manhattan_distance(&b), 8);
+        assert_eq!(a.chebyshev_distance(&b), 6);
+        assert_eq!(Position::new(3usize, 0).manhattan_distance(&Position::new(0, 4)), 7);
+        assert_eq!(Position::new(1i32, 1).chebyshev_distance(&Position::new(2, 2)), 1);
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("498,4".parse(), Ok(Position::new(498usize, 4)));
+        assert_eq!("-3, 7".parse(), Ok(Position::new(-3i64, 7)));
+        assert!("498".parse::<Position>().is_err());
+        assert!("a,4".parse::<Position>().is_err());
+    }
+
+    #[test]
+    fn test_grid() {
+        let grid = grid("abc\ndef\n");
+        assert_eq!(grid.dim(), (2, 3));
+        assert_eq!(grid[[1, 0]], b'd');
+        assert_eq!(grid[[0, 2]], b'c');
+    }
+
+    #[test]
+    fn test_neighbours() {
+        assert_eq!(neighbours(&Position::new(0, 0), (2, 3)), vec![Position::new(1, 0), Position::new(0, 1)]);
+        assert_eq!(neighbours(&Position::new(1, 2), (2, 3)), vec![Position::new(0, 2), Position::new(1, 1)]);
+        assert_eq!(neighbours(&Position::new(1, 1), (3, 3)).len(), 4);
+    }
+}
@@ -0,0 +1,59 @@
+use crate::answer::Answer;
+use crate::solution::Solution;
+
+pub fn part1(input: &str) -> i64 {
+    let mut count = 0;
+    for char in input.chars() {
+        match char {
+            '(' => count += 1,
+            ')' => count -= 1,
+            _ => {},
+        }
+    }
+    count
+}
+
+pub fn part2(input: &str) -> Option<usize> {
+    let mut count = 0;
+    for (i, char) in input.chars().enumerate() {
+        match char {
+            '(' => count += 1,
+            ')' => count -= 1,
+            _ => {},
+        }
+        if count == -1 {
+            return Some(i + 1);
+        }
+    }
+    None
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = String;
+
+    fn year(&self) -> u32 {
+        2015
+    }
+
+    fn day(&self) -> u32 {
+        1
+    }
+
+    fn parse(&self, input: &str) -> String {
+        input.to_string()
+    }
+
+    fn parses(&self) -> bool {
+        false
+    }
+
+    fn part1(&self, input: &String) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(&self, input: &String) -> Option<Answer> {
+        part2(input).map(Answer::from)
+    }
+}
@@ -0,0 +1,94 @@
+use std::str::FromStr;
+use rayon::prelude::*;
+use crate::answer::Answer;
+use crate::solution::Solution;
+
+#[derive(Debug)]
+pub struct BoxDimension {
+    length: i64,
+    width: i64,
+    height: i64
+}
+
+impl FromStr for BoxDimension {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parts: Vec<&str> = s.split("x").collect();
+        let length = parts[0].parse().unwrap();
+        let width = parts[1].parse().unwrap();
+        let height = parts[2].parse().unwrap();
+        Ok(BoxDimension { length, width, height })
+    }
+}
+
+fn parse(input: &str) -> Vec<BoxDimension> {
+    //read line by line, check for empty lines and store in a vector if not empty
+    let numbers: Vec<BoxDimension> = input
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| line.parse().expect("Error parsing input"))
+        .collect();
+    numbers
+}
+
+fn total_paper_needed(dims: &[BoxDimension]) -> i64 {
+    let mut total = 0;
+    for dim in dims {
+        let l = dim.length;
+        let w = dim.width;
+        let h = dim.height;
+        let lw = l * w;
+        let wh = w * h;
+        let hl = h * l;
+        let min = lw.min(wh).min(hl);
+        total += 2 * lw + 2 * wh + 2 * hl + min;
+    }
+    total
+}
+
+fn total_ribbon_needed(dims: &[BoxDimension]) -> i64 {
+    dims.par_iter()
+        .map(|dim| {
+            let mut ordered_dim = [dim.length, dim.width, dim.height];
+            ordered_dim.sort();
+            ordered_dim[0]*2 + ordered_dim[1]*2 + ordered_dim[0]*ordered_dim[1]*ordered_dim[2]
+        })
+        .sum()
+}
+
+pub fn part1(input: &str) -> i64 {
+    let box_dimentions = parse(input);
+    total_paper_needed(&box_dimentions)
+}
+
+pub fn part2(input: &str) -> i64 {
+    let box_dimentions = parse(input);
+    total_ribbon_needed(&box_dimentions)
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<BoxDimension>;
+
+    fn year(&self) -> u32 {
+        2015
+    }
+
+    fn day(&self) -> u32 {
+        2
+    }
+
+    fn parse(&self, input: &str) -> Vec<BoxDimension> {
+        parse(input)
+    }
+
+    fn part1(&self, input: &Vec<BoxDimension>) -> Answer {
+        total_paper_needed(input).into()
+    }
+
+    fn part2(&self, input: &Vec<BoxDimension>) -> Option<Answer> {
+        Some(total_ribbon_needed(input).into())
+    }
+}
@@ -0,0 +1,74 @@
+use ndarray::Array2;
+use crate::answer::Answer;
+use crate::solution::Solution;
+
+fn next_house(char: char, houses: &mut Array2<i32>, x: &mut usize, y: &mut usize) {
+    match char {
+        '^' => *y += 1,
+        'v' => *y -= 1,
+        '>' => *x += 1,
+        '<' => *x -= 1,
+        _ => (),
+    }
+    let x = *x;
+    let y = *y;
+    houses[[x, y]] += 1;
+}
+
+pub fn part1(line: &str) -> i64 {
+    let mut houses: Array2<i32> = Array2::zeros((1000, 1000));
+    let mut x = 500;
+    let mut y = 500;
+    houses[[x, y]] = 1;
+    for char in line.chars() {
+        next_house(char, &mut houses, &mut x, &mut y);
+    }
+    houses.iter().filter(|&&x| x > 0).count() as i64
+}
+
+pub fn part2(line: &str) -> i64 {
+    let mut houses: Array2<i32> = Array2::zeros((1000, 1000));
+    let mut x1 = 500;
+    let mut y1 = 500;
+    let mut x2 = 500;
+    let mut y2 = 500;
+    houses[[x1, y1]] = 2;
+    for (i, char) in line.chars().enumerate() {
+        if i % 2 == 0 {
+            next_house(char, &mut houses, &mut x1, &mut y1);
+        } else {
+            next_house(char, &mut houses, &mut x2, &mut y2);
+        }
+    }
+    houses.iter().filter(|&&x| x > 0).count() as i64
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = String;
+
+    fn year(&self) -> u32 {
+        2015
+    }
+
+    fn day(&self) -> u32 {
+        3
+    }
+
+    fn parse(&self, input: &str) -> String {
+        input.to_string()
+    }
+
+    fn parses(&self) -> bool {
+        false
+    }
+
+    fn part1(&self, input: &String) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(&self, input: &String) -> Option<Answer> {
+        Some(part2(input).into())
+    }
+}
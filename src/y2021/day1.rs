@@ -0,0 +1,79 @@
+use crate::answer::Answer;
+use crate::solution::Solution;
+
+fn number_of_increases(nums: &[u32]) -> u32 {
+    //if 0 or 1 numbers, no increases
+    if nums.len() < 2 {
+        return 0;
+    }
+    let mut i  = 1;
+    let mut count = 0;
+    while i < nums.len() {
+        if nums[i] > nums[i-1] {
+            count += 1
+        }
+        i += 1
+    }
+    count
+}
+
+fn sliding_window_increases(nums: &[u32]) -> u32 {
+    if nums.len() < 4 {
+        return 0;
+    }
+    let mut i = 3;
+    let mut count = 0;
+    while i < nums.len() {
+        if nums[i-2..i+1].iter().sum::<u32>() > nums[i-3..i].iter().sum::<u32>() {
+            count += 1
+        }
+        i += 1
+    }
+    count
+}
+
+fn parse(input: &str) -> Vec<u32> {
+    //read line by line, check for empty lines and store in a vector if not empty
+    let numbers: Vec<u32> = input
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| line.parse().expect("Error parsing input"))
+        .collect();
+    numbers
+}
+
+pub fn part1(input: &str) -> u32 {
+    let numbers = parse(input);
+    number_of_increases(&numbers)
+}
+
+pub fn part2(input: &str) -> u32 {
+    let numbers = parse(input);
+    sliding_window_increases(&numbers)
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = Vec<u32>;
+
+    fn year(&self) -> u32 {
+        2021
+    }
+
+    fn day(&self) -> u32 {
+        1
+    }
+
+    fn parse(&self, input: &str) -> Vec<u32> {
+        parse(input)
+    }
+
+    fn part1(&self, input: &Vec<u32>) -> Answer {
+        number_of_increases(input).into()
+    }
+
+    fn part2(&self, input: &Vec<u32>) -> Option<Answer> {
+        Some(sliding_window_increases(input).into())
+    }
+}
@@ -0,0 +1,102 @@
+use std::str::FromStr;
+use crate::answer::Answer;
+use crate::solution::Solution;
+
+#[derive(Debug)]
+pub struct Move {
+    direction: String,
+    distance: i64,
+}
+
+impl FromStr for Move {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parts: Vec<&str> = s.split(" ").collect();
+        let direction = parts[0].parse().unwrap();
+        let distance = parts[1].parse().unwrap();
+        Ok(Move { direction, distance })
+    }
+}
+
+fn parse(input: &str) -> Vec<Move> {
+    //read line by line, check for empty lines and store in a vector if not empty
+    let numbers: Vec<Move> = input
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| line.parse().expect("Error parsing input"))
+        .collect();
+    numbers
+}
+
+fn do_moves(moves: &[Move]) -> (i64, i64) {
+    let mut depth = 0;
+    let mut h_position = 0;
+    for m in moves {
+        match m.direction.as_str() {
+            "forward" => h_position += m.distance,
+            "up" => depth -= m.distance,
+            "down" => depth += m.distance,
+            _ => (),
+        }
+    }
+    (depth, h_position)
+}
+
+fn do_moves_with_aim(moves: &[Move]) -> (i64, i64) {
+    let mut depth = 0;
+    let mut h_position = 0;
+    let mut aim = 0;
+    for m in moves {
+        match m.direction.as_str() {
+            "forward" => {
+                h_position += m.distance; 
+                depth += aim * m.distance;
+            },
+            "up" => aim -= m.distance,
+            "down" => aim += m.distance,
+            _ => (),
+        }
+    }
+    (depth, h_position)
+}
+
+pub fn part1(input: &str) -> i64 {
+    let moves = parse(input);
+    let (depth, h_position) = do_moves(&moves);
+    depth * h_position
+}
+
+pub fn part2(input: &str) -> i64 {
+    let moves = parse(input);
+    let (depth, h_position) = do_moves_with_aim(&moves);
+    depth * h_position
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<Move>;
+
+    fn year(&self) -> u32 {
+        2021
+    }
+
+    fn day(&self) -> u32 {
+        2
+    }
+
+    fn parse(&self, input: &str) -> Vec<Move> {
+        parse(input)
+    }
+
+    fn part1(&self, input: &Vec<Move>) -> Answer {
+        let (depth, h_position) = do_moves(input);
+        (depth * h_position).into()
+    }
+
+    fn part2(&self, input: &Vec<Move>) -> Option<Answer> {
+        let (depth, h_position) = do_moves_with_aim(input);
+        Some((depth * h_position).into())
+    }
+}
@@ -0,0 +1,57 @@
+use crate::answer::Answer;
+use crate::solution::Solution;
+
+fn most_common_bit(numbers: &[u32], position: usize) -> u32 {
+    let mut ones_count = 0;
+    let mut zeros_count = 0;
+    for number in numbers {
+        if number & (1 << position) == 0 {
+            zeros_count += 1;
+        } else {
+            ones_count += 1;
+        }
+    }
+    if ones_count >= zeros_count {
+        1
+    } else {
+        0
+    }
+}
+
+pub fn part1(input: &str) -> u32 {
+    //lines are strings of 12 1s and 0s, parse to u32
+    let numbers = input.lines().map(|line| u32::from_str_radix(line, 2).unwrap()).collect::<Vec<u32>>();
+    let gamma_rate: u32 = (0..12).map(|position| most_common_bit(&numbers, position) << position).sum();
+    let epsilon_rate: u32 = (0..12).map(|position| (most_common_bit(&numbers, position) ^ 1) << position).sum();
+    gamma_rate * epsilon_rate
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = String;
+
+    fn year(&self) -> u32 {
+        2021
+    }
+
+    fn day(&self) -> u32 {
+        3
+    }
+
+    fn parts(&self) -> u32 {
+        1
+    }
+
+    fn parse(&self, input: &str) -> String {
+        input.to_string()
+    }
+
+    fn parses(&self) -> bool {
+        false
+    }
+
+    fn part1(&self, input: &String) -> Answer {
+        part1(input).into()
+    }
+}
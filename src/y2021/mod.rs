@@ -0,0 +1,13 @@
+use crate::solution::DynSolution;
+
+pub mod day1;
+pub mod day2;
+pub mod day3;
+
+pub fn solutions() -> Vec<&'static dyn DynSolution> {
+    vec![
+        &day1::Day1,
+        &day2::Day2,
+        &day3::Day3,
+    ]
+}
@@ -0,0 +1,102 @@
+use rayon::prelude::*;
+use crate::answer::Answer;
+use crate::solution::{Alternative, Solution};
+
+pub fn solve_aoc_functional(input: &str) -> (usize, usize) {
+    let mut input_text: Vec<usize> = input
+        .trim()
+        .split("\n\n")
+        .map(|s| s
+                .lines()
+                .flat_map(str::parse::<usize>)
+                .sum::<usize>())
+        .collect::<Vec<usize>>();
+    input_text.sort_unstable_by(|a, b| b.cmp(a));
+    (input_text[0], input_text[0..3].iter().sum::<usize>())
+    //println!("Part1: {:?}", input_text[0]);
+    //println!("Part2: {:?}", input_text[0..3].iter().sum::<usize>());
+}
+
+pub fn solve_aoc(input: &str) -> (usize, usize) {
+    let split_input = input.split("\n\n");
+    let mut total_calories: Vec<usize> = Vec::new();
+    for part in split_input {
+        let mut numbers = Vec::new();
+        for number in part.lines() {
+            numbers.push(number.parse::<usize>().expect("Error parsing input"));
+        }
+        total_calories.push(numbers.iter().sum())
+    }
+    total_calories.sort_unstable();
+    total_calories.reverse();
+    (total_calories[0], total_calories[0..3].iter().sum::<usize>())
+    //println!("Part1: {:?}", total_calories[0]);
+    //println!("Part2: {:?}", total_calories[0..3].into_iter().sum::<usize>());
+}
+
+pub fn solve_aoc_parallel(input: &str) -> (usize, usize) {
+    let input_text = input
+        .trim()
+        .split("\n\n")
+        .collect::<Vec<&str>>();
+    //parse in parallel
+    let mut total_calories = input_text
+        .into_par_iter()
+        .map(|part| part
+             .lines()
+             .flat_map(str::parse::<usize>)
+             .sum::<usize>())
+        .collect::<Vec<usize>>();
+    total_calories.sort_unstable_by(|a, b| b.cmp(a));
+    (total_calories[0], total_calories[0..3].iter().sum::<usize>())
+    //println!("Part1: {:?}", total_calories[0]);
+    //println!("Part2: {:?}", total_calories[0..3].iter().sum::<usize>());
+    
+}
+
+pub fn part1(input: &str) -> usize {
+    solve_aoc(input).0
+}
+
+pub fn part2(input: &str) -> usize {
+    solve_aoc(input).1
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = String;
+
+    fn year(&self) -> u32 {
+        2022
+    }
+
+    fn day(&self) -> u32 {
+        1
+    }
+
+    fn parse(&self, input: &str) -> String {
+        input.to_string()
+    }
+
+    fn parses(&self) -> bool {
+        false
+    }
+
+    fn part1(&self, input: &String) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(&self, input: &String) -> Option<Answer> {
+        Some(part2(input).into())
+    }
+
+    fn alternatives(&self) -> Vec<Alternative<String>> {
+        vec![
+            Alternative { part: 1, name: "functional", solve: |input| solve_aoc_functional(input).0.into() },
+            Alternative { part: 2, name: "functional", solve: |input| solve_aoc_functional(input).1.into() },
+            Alternative { part: 1, name: "parallel", solve: |input| solve_aoc_parallel(input).0.into() },
+            Alternative { part: 2, name: "parallel", solve: |input| solve_aoc_parallel(input).1.into() },
+        ]
+    }
+}
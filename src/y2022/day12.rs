@@ -0,0 +1,184 @@
+use std::str::FromStr;
+use std::cmp::Ordering;
+use ndarray::prelude::*;
+use std::collections::{HashMap, VecDeque, BinaryHeap};
+use crate::answer::Answer;
+use crate::solution::Solution;
+use crate::util::{grid, neighbours};
+
+type Position = crate::util::Position<usize>;
+
+#[cfg(test)]
+mod test{
+    use super::*;
+    fn test_input() -> &'static str {
+"Sabqponm
+abcryxxl
+accszExk
+acctuvwj
+abdefghi"}
+
+    #[test]
+    fn part1_test() {
+        assert_eq!(part1(test_input()), 31);
+    }
+    #[test]
+    fn part2_test() {
+        assert_eq!(part2(test_input()), 29);
+    }
+}
+
+pub struct HeightMap{ 
+    map: Array2<u8>,
+    start: Position,
+    end: Position,
+}
+
+impl HeightMap {
+    fn get_successors(&self, node: &Position) -> Vec<Position> {
+        neighbours(node, self.map.dim())
+            .into_iter()
+            .filter(|next| self.map[[next.x, next.y]] <= self.map[[node.x, node.y]] + 1)
+            .collect()
+    }
+
+    fn get_successors2(&self, node: &Position) -> Vec<Position> {
+        neighbours(node, self.map.dim())
+            .into_iter()
+            .filter(|next| self.map[[next.x, next.y]] + 1 >= self.map[[node.x, node.y]])
+            .collect()
+    }
+
+    fn search(&self, start: &Position, end: &Position) -> i64 {
+        let mut visited = HashMap::new();
+        let mut heap: BinaryHeap<Node> = BinaryHeap::new();
+        heap.push(Node{position:*start, depth: 0, min_distance: start.manhattan_distance(end)});
+
+        while let Some(node) = heap.pop() {
+            let successors = self.get_successors(&node.position);
+            for next_node in successors {
+                if next_node == *end {
+                    return node.depth+1;
+                }
+                if !visited.contains_key(&next_node) {
+                    heap.push(Node{position:next_node, depth:node.depth+1 , min_distance: node.depth+1+next_node.manhattan_distance(end)});
+                }
+            }
+            visited.insert(node.position, true);
+        }
+        -1
+    }
+
+    fn search2(&self, start: &Position) -> i64 {
+        //minimum steps to reach map[x][y] == 0
+        let mut queue = VecDeque::new();
+        let mut visited = HashMap::new();
+        queue.push_back((*start, 0));
+
+        while !queue.is_empty() {
+            let (node, depth) = queue.pop_front().unwrap();
+            let successors = self.get_successors2(&node);
+            for next_node in successors {
+                if self.map[[next_node.x, next_node.y]] == 0 {
+                    return depth+1;
+                }
+                if visited.insert(next_node, true).is_none() {
+                    queue.push_back((next_node, depth+1));
+                }
+            }
+        }
+        -1
+    }
+
+    fn nb_steps(&self) -> i64 {
+        self.search(&self.start, &self.end)
+    }
+
+    fn nb_steps2(&self) -> i64 {
+        self.search2(&self.end)
+    }
+}
+
+#[derive(Debug, Eq, PartialEq)]
+struct Node {
+    position: Position,
+    depth: i64,
+    min_distance: i64,
+}
+
+impl PartialOrd for Node {
+    fn partial_cmp(&self, other: &Node) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Node {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.min_distance.cmp(&self.min_distance)
+    }
+}
+
+impl FromStr for HeightMap {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut start = Position::default();
+        let mut end = Position::default();
+        let mut map = grid(s);
+        for ((i, j), height) in map.indexed_iter_mut() {
+            match *height as char {
+                'S' => {
+                    start = Position::new(i, j);
+                    *height = 0;
+                }
+                'E' => {
+                    end = Position::new(i, j);
+                    *height = 25;
+                }
+                c => {
+                    *height = (c as u8) - 97
+                }
+            }
+        }
+        Ok(HeightMap {
+            map,
+            start,
+            end,
+        })
+    }
+}
+
+pub fn part1(input: &str) -> i64 {
+    let map: HeightMap = input.parse().unwrap();
+    map.nb_steps()
+}
+
+pub fn part2(input: &str) -> i64 {
+    let map: HeightMap = input.parse().unwrap();
+    map.nb_steps2()
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = HeightMap;
+
+    fn year(&self) -> u32 {
+        2022
+    }
+
+    fn day(&self) -> u32 {
+        12
+    }
+
+    fn parse(&self, input: &str) -> HeightMap {
+        input.parse().unwrap()
+    }
+
+    fn part1(&self, input: &HeightMap) -> Answer {
+        input.nb_steps().into()
+    }
+
+    fn part2(&self, input: &HeightMap) -> Option<Answer> {
+        Some(input.nb_steps2().into())
+    }
+}
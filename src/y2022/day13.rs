@@ -0,0 +1,205 @@
+use std::str::FromStr;
+use std::cmp::Ordering;
+use nom::character::complete::char;
+use nom::character::complete::digit1;
+use nom::sequence::delimited;
+use nom::multi::separated_list0;
+use nom::branch::alt;
+use crate::answer::Answer;
+use crate::solution::Solution;
+
+#[cfg(test)]
+mod test{
+    use super::*;
+    fn test_input() -> &'static str {
+"[1,1,3,1,1]
+[1,1,5,1,1]
+
+[[1],[2,3,4]]
+[[1],4]
+
+[9]
+[[8,7,6]]
+
+[[4,4],4,4]
+[[4,4],4,4,4]
+
+[7,7,7,7]
+[7,7,7]
+
+[]
+[3]
+
+[[[]]]
+[[]]
+
+[1,[2,[3,[4,[5,6,7]]]],8,9]
+[1,[2,[3,[4,[5,6,0]]]],8,9]"}
+
+    #[test]
+    fn part1_test() {
+        assert_eq!(part1(test_input()), 13);
+    }
+
+    #[test]
+    fn part2_test() {
+        assert_eq!(part2(test_input()), 140);
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+enum Value {
+    Int(i64),
+    List(Vec<Value>),
+}
+
+impl PartialOrd for Value {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Value {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Value::Int(a), Value::Int(b)) => a.cmp(b),
+            (Value::List(a), Value::List(b)) => a.cmp(b),
+            (Value::Int(a), Value::List(_)) => Value::List(vec![Value::Int(*a)]).cmp(other),
+            (Value::List(_), Value::Int(b)) => self.cmp(&Value::List(vec![Value::Int(*b)])),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone)]
+struct Packet {
+    content: Value,
+}
+
+
+impl Packet {
+    fn parse_list(input: &str) -> nom::IResult<&str, Value> {
+        let parsed = delimited(
+                char('['),
+                separated_list0(
+                    char(','),
+                    Packet::parse_value),
+                char(']'))(input);
+        match parsed {
+            Ok((i, v)) => Ok((i, Value::List(v))),
+            Err(e) => Err(e),
+        }
+    }
+    
+    fn parse_number(input: &str) -> nom::IResult<&str, Value> {
+        let parsed = digit1(input);
+        match parsed {
+            Ok((i, v)) => Ok((i, Value::Int(v.parse().unwrap()))),
+            Err(e) => Err(e),
+        }
+    }
+
+    fn parse_value(input: &str) -> nom::IResult<&str, Value> {
+        alt((
+            Packet::parse_number,
+            Packet::parse_list,
+        ))(input)
+    }
+}
+
+impl FromStr for Packet {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(());
+        }
+        let (input, value) = Packet::parse_value(s).unwrap();
+        if !input.is_empty() {
+            return Err(());
+        }
+        Ok(Packet{content: value})
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Hash, Clone)]
+struct Pair {
+    first: Packet,
+    second: Packet,
+}
+
+impl FromStr for Pair {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines = s.lines();
+        Ok(Pair {
+            first: lines.next().unwrap().parse().unwrap(),
+            second: lines.next().unwrap().parse().unwrap(),
+        })
+    }
+}
+
+impl Pair { 
+    fn is_right_order(&self) -> bool {
+        self.first < self.second
+    }
+}
+
+pub fn part1(input: &str) -> usize {
+    let pairs: Vec<Pair> = input.split("\n\n").map(|s| s.parse().unwrap()).collect();
+    let mut total = 0;
+    for (i, pair) in pairs.iter().enumerate() {
+        if pair.is_right_order(){
+            total += i+1;
+        }
+    }
+    total
+}
+
+pub fn part2(input: &str) -> usize {
+    let mut packets: Vec<Packet> = input.lines().flat_map(|s| s.parse()).collect();
+    let divider1 = "[[2]]".parse::<Packet>().unwrap();
+    let divider2 = "[[6]]".parse::<Packet>().unwrap();
+    packets.push(divider1.clone());
+    packets.push(divider2.clone());
+    packets.sort();
+    let mut total = 1;
+    for (i, packet) in packets.iter().enumerate() {
+        if packet == &divider1 {
+            total *= i+1;
+        }
+        if packet == &divider2 {
+            total *= i+1;
+        }
+    }
+    total
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = String;
+
+    fn year(&self) -> u32 {
+        2022
+    }
+
+    fn day(&self) -> u32 {
+        13
+    }
+
+    fn parse(&self, input: &str) -> String {
+        input.to_string()
+    }
+
+    fn parses(&self) -> bool {
+        false
+    }
+
+    fn part1(&self, input: &String) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(&self, input: &String) -> Option<Answer> {
+        Some(part2(input).into())
+    }
+}
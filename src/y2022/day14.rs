@@ -0,0 +1,174 @@
+use ndarray::prelude::*;
+use std::fmt;
+use std::str::{FromStr, from_utf8};
+use crate::answer::Answer;
+use crate::solution::Solution;
+
+type Point = crate::util::Position<usize>;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    fn test_input() -> &'static str {
+"498,4 -> 498,6 -> 496,6
+503,4 -> 502,4 -> 502,9 -> 494,9"
+    }
+    
+    #[test]
+    fn part1_test() {
+        assert_eq!(part1(test_input()), 24);
+    }
+    #[test]
+    fn part2_test() {
+        assert_eq!(part2(test_input()), 93);
+    }
+}
+
+pub struct Scan {
+    lines: Vec<Line>,
+    max_y: usize,
+}
+
+impl FromStr for Scan {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let points: Vec<Vec<Point>> = s.lines().map(|line| line.split(" -> ").map(|point| point.parse().unwrap()).collect()).collect();
+        let lines: Vec<Line> = points.iter().flat_map(|vp| vp.windows(2).map(|wp| Line{start: wp[0], end: wp[1]})).collect();
+        let max_y = lines.iter().map(|line| line.start.y.max(line.end.y)).max().unwrap();
+        Ok(Scan{lines, max_y})
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Hash, Clone)]
+struct Line {
+    start:Point,
+    end:Point,
+}
+ 
+
+struct Map {
+    map: Array2<u8>,
+    offset_x: usize,
+}
+
+impl fmt::Display for Map {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let rows = self.map.rows();
+        for row in rows {
+            writeln!(f, "{}", from_utf8(&row.to_vec()).unwrap())?;
+        }
+        Ok(())
+    }
+}
+
+impl Map {
+    fn new(scan: &Scan, has_floor: bool) -> Map {
+        let mut map = Array2::from_elem((scan.max_y+3, 1000), b'.');
+        
+        if has_floor{
+            for x in 0..map.dim().1 {
+                let y = map.dim().0-1;
+                map[[y, x]] = b'#';
+            }
+        }
+        
+        map[[0,500]] = b'+';
+        for line in scan.lines.iter() {
+            if line.start.x == line.end.x {
+                for y in line.start.y..=line.end.y {
+                    map[[y, line.start.x]] = b'#';
+                }
+                for y in line.end.y..=line.start.y {
+                    map[[y, line.start.x]] = b'#';
+                }
+            } else if line.start.y == line.end.y {
+                for x in line.start.x..=line.end.x {
+                    map[[line.start.y, x]] = b'#';
+                }
+                for x in line.end.x..=line.start.x {
+                    map[[line.start.y, x]] = b'#';
+                }
+            } else {
+                panic!("Not a line");
+            }
+        }
+        Map {map, offset_x:0}
+    }
+
+    fn add_sand(&mut self, x: usize, y: usize) -> bool {
+        let mut s = Point {x,y};
+        while s.y < self.map.dim().0-1 {
+            if self.map[[s.y+1, s.x]] == b'.' {
+                s.y += 1;
+                continue;
+            }
+            if self.map[[s.y+1, s.x-1]] == b'.' {
+                s.x -= 1;
+                s.y += 1;
+                continue;
+            }
+            if self.map[[s.y+1, s.x+1]] == b'.' {
+                s.x += 1;
+                s.y += 1;
+                continue;
+            }
+            self.map[[s.y, s.x]] = b'o';
+            if s.x == 500 && s.y == 0 {
+                return false;
+            }
+            return true
+        }
+        false
+    }
+
+    fn fill(&mut self) {
+        while self.add_sand(500-self.offset_x, 0) {}
+
+    }
+
+    fn count_sand(&self) -> usize {
+        self.map.iter().filter(|&&c| c == b'o').count()
+    }
+}
+
+fn sand_count(lines: &Scan, has_floor: bool) -> usize {
+    let mut map = Map::new(lines, has_floor);
+    map.fill();
+    map.count_sand()
+}
+
+pub fn part1(input: &str) -> usize {
+    let lines = input.parse::<Scan>().unwrap();
+    sand_count(&lines, false)
+}
+
+pub fn part2(input: &str) -> usize {
+    let lines = input.parse::<Scan>().unwrap();
+    sand_count(&lines, true)
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Scan;
+
+    fn year(&self) -> u32 {
+        2022
+    }
+
+    fn day(&self) -> u32 {
+        14
+    }
+
+    fn parse(&self, input: &str) -> Scan {
+        input.parse().unwrap()
+    }
+
+    fn part1(&self, input: &Scan) -> Answer {
+        sand_count(input, false).into()
+    }
+
+    fn part2(&self, input: &Scan) -> Option<Answer> {
+        Some(sand_count(input, true).into())
+    }
+}
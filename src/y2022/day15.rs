@@ -0,0 +1,182 @@
+use std::str::FromStr;
+use std::collections::HashSet;
+use crate::answer::Answer;
+use crate::solution::Solution;
+use crate::util::Position;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    fn test_input() -> &'static str {
+"Sensor at x=2, y=18: closest beacon is at x=-2, y=15
+Sensor at x=9, y=16: closest beacon is at x=10, y=16
+Sensor at x=13, y=2: closest beacon is at x=15, y=3
+Sensor at x=12, y=14: closest beacon is at x=10, y=16
+Sensor at x=10, y=20: closest beacon is at x=10, y=16
+Sensor at x=14, y=17: closest beacon is at x=10, y=16
+Sensor at x=8, y=7: closest beacon is at x=2, y=10
+Sensor at x=2, y=0: closest beacon is at x=2, y=10
+Sensor at x=0, y=11: closest beacon is at x=2, y=10
+Sensor at x=20, y=14: closest beacon is at x=25, y=17
+Sensor at x=17, y=20: closest beacon is at x=21, y=22
+Sensor at x=16, y=7: closest beacon is at x=15, y=3
+Sensor at x=14, y=3: closest beacon is at x=15, y=3
+Sensor at x=20, y=1: closest beacon is at x=15, y=3"
+    }
+    
+    #[test]
+    fn part1_test() {
+        assert_eq!(part1(test_input(), 10), 26);
+    }
+    #[test]
+    fn part2_test() {
+        assert_eq!(part2(test_input(), 20), 56000011);
+    }
+}
+
+struct Beacon {
+    position: Position,
+}
+
+pub struct Sensor {
+    position: Position,
+    beacon: Beacon,
+    radius: i64,
+}
+
+impl FromStr for Sensor {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let re = regex::Regex::new(r"Sensor at x=(-?\d+), y=(-?\d+): closest beacon is at x=(-?\d+), y=(-?\d+)").unwrap();
+        let captures = re.captures(s).unwrap();
+        let sensor_x = captures.get(1).unwrap().as_str().parse::<i64>().unwrap();
+        let sensor_y = captures.get(2).unwrap().as_str().parse::<i64>().unwrap();
+        let beacon_x = captures.get(3).unwrap().as_str().parse::<i64>().unwrap();
+        let beacon_y = captures.get(4).unwrap().as_str().parse::<i64>().unwrap();
+        let sensor_position = Position::new(sensor_x, sensor_y);
+        let beacon_position = Position::new(beacon_x, beacon_y);
+        let radius = sensor_position.manhattan_distance(&beacon_position);
+        Ok(Sensor {
+            position: sensor_position,
+            beacon: Beacon {
+                position: beacon_position,
+            },
+            radius,
+        })
+    }
+}
+
+impl Sensor {
+    fn covered_positions_on_line(&self, y: i64) -> Vec<i64> {
+        let mut positions = Vec::new();
+        let y_diff = (self.position.y - y).abs();
+        if y_diff > self.radius {
+            return positions;
+        }
+        let number_of_positions = 1 + (self.radius - y_diff) * 2;
+        let first_position = self.position.x - number_of_positions / 2;
+        for i in 0..number_of_positions {
+            positions.push(first_position + i);
+        }
+        positions
+    }
+}
+
+fn covered_positions_on_line(sensors: &Vec<Sensor>, y: i64) -> usize {
+    let mut positions = HashSet::new();
+    for sensor in sensors {
+        let covered_positions = sensor.covered_positions_on_line(y);
+        positions.extend(covered_positions);
+    }
+    for sensor in sensors {
+        if sensor.position.y == y {
+            positions.remove(&sensor.position.x);
+        }
+        if sensor.beacon.position.y == y {
+            positions.remove(&sensor.beacon.position.x);
+        }
+    }
+    positions.len()
+}
+
+fn beacon_position(sensors: &Vec<Sensor>, max: i64) -> Position {
+    for sensor in sensors {
+        let distance = sensor.radius + 1;
+        for i in 0..distance {
+            let a = Position::new(sensor.position.x + i, sensor.position.y + distance - i);
+            let b = Position::new(sensor.position.x - i, sensor.position.y + distance - i);
+            let c = Position::new(sensor.position.x + i, sensor.position.y - distance + i);
+            let d = Position::new(sensor.position.x - i, sensor.position.y - distance + i);
+            if !is_covered(&a, sensors, &max) {
+                return a
+            }
+            if !is_covered(&b, sensors, &max) {
+                return b
+            }
+            if !is_covered(&c, sensors, &max) {
+                return c
+            }
+            if !is_covered(&d, sensors, &max) {
+                return d
+            }
+        }
+    }
+    panic!("No beacon position found");
+}
+
+fn is_covered(position: &Position, sensors: &Vec<Sensor>, max: &i64) -> bool {
+    if position.y > *max || position.x > *max || position.y < 0 || position.x < 0 {
+        return true;
+    }
+    for sensor in sensors {
+        let distance_to_position = sensor.position.manhattan_distance(position);
+        if distance_to_position <= sensor.radius {
+            return true;
+        }
+    }
+    false
+}
+
+fn parse(input: &str) -> Vec<Sensor> {
+    input.lines().map(|line| line.parse::<Sensor>().unwrap()).collect::<Vec<_>>()
+}
+
+fn tuning_frequency(sensors: &Vec<Sensor>, max: i64) -> i64 {
+    let position = beacon_position(sensors, max);
+    position.x * 4000000 + position.y
+}
+
+pub fn part1(input: &str, y: i64) -> usize {
+    covered_positions_on_line(&parse(input), y)
+}
+
+pub fn part2(input: &str, max: i64) -> i64 {
+    tuning_frequency(&parse(input), max)
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Vec<Sensor>;
+
+    fn year(&self) -> u32 {
+        2022
+    }
+
+    fn day(&self) -> u32 {
+        15
+    }
+
+    fn parse(&self, input: &str) -> Vec<Sensor> {
+        parse(input)
+    }
+
+    fn part1(&self, input: &Vec<Sensor>) -> Answer {
+        covered_positions_on_line(input, 2000000).into()
+    }
+
+    fn part2(&self, input: &Vec<Sensor>) -> Option<Answer> {
+        Some(tuning_frequency(input, 4000000).into())
+    }
+}
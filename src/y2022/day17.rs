@@ -0,0 +1,222 @@
+use std::fmt::Display;
+use std::collections::{ VecDeque, HashMap };
+use ndarray::prelude::*;
+use crate::answer::Answer;
+use crate::solution::Solution;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_input() ->  &'static str {
+        ">>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>"
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(test_input()), 3068);
+    }
+    
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(test_input()), 1514285714288);
+    }
+   
+}
+
+type Position = crate::util::Position<usize>;
+
+
+#[derive(Debug, Clone)]
+struct Block {
+    position: Position,
+    shape: Vec<(usize, usize)>,
+}
+
+impl Block {
+    fn new(position: Position, shape: usize) -> Self {
+        let shapes = [
+        vec![(0,0), (1,0), (2,0), (3,0)],
+        vec![(0,1), (1,0), (1,1), (1,2), (2,1)],
+        vec![(0,0), (1,0), (2,0), (2,1), (2,2)],
+        vec![(0,0), (0,1), (0,2), (0,3)],
+        vec![(0,0), (1,0), (0,1), (1,1)]];
+        let i = shape % shapes.len();
+        Self { position, shape: shapes[i].clone() }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Chamber {
+    map: Array2<u8>,
+    block: Block,
+    block_no: usize,
+    max_height: usize,
+}
+
+impl Display for Chamber {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut s = String::new();
+        let mut map = self.map.clone();
+        for (x, y) in &self.block.shape {
+            map[[self.block.position.x + x, self.block.position.y + y]] = b'@';
+        }
+        let start = 20.max(self.max_height) - 20;
+        let end = 20.max(self.max_height) + 10;
+        for j in (start..end).rev() {
+            for i in 0..map.shape()[0] {
+                s.push(map[[i,j]] as char);
+            }
+            s.push('\n');
+        }
+        write!(f, "{s}")
+    }
+}
+
+impl Chamber {
+    fn new(height: usize) -> Self {
+        //map
+        let width = 9;
+        let mut map = Array2::from_elem((width,height), b'.');
+        for i in 1..width-1 {
+            map[[i, 0]] = b'-';
+        }
+        for i in 1..height {
+            map[[0,i]] = b'|';
+            map[[width-1,i]] = b'|';
+        }
+        map[[0,0]] = b'+';
+        map[[width-1,0]] = b'+';
+        //Block
+        let max_height = 0;
+        let position = Position { x: 3, y: max_height + 4 };
+        let block_no = 0;
+        let block = Block::new(position, block_no);
+
+        Chamber { map, block, block_no, max_height }
+    }
+
+    fn next_block(&mut self) {
+        self.block_no += 1;
+        self.block = Block::new(Position { x: 3, y: self.max_height+4 }, self.block_no);
+    }
+
+    fn will_colide(&self, x: i64, y: i64) -> bool {
+        for (i, j) in &self.block.shape {
+            let new_x = self.block.position.x as i64 + x + *i as i64;
+            let new_y = self.block.position.y as i64 + y + *j as i64;
+            if self.map[[new_x as usize, new_y as usize]] != b'.'{
+                return true
+            }
+        }
+        false
+    }
+
+
+    fn next_move(&mut self, direction: char) {
+        let (x, y): (i64, i64) = match direction {
+            '>' => (1, 0),
+            '<' => (-1, 0),
+            _ => (0, 0),
+        };
+
+        if !self.will_colide(x, y) {
+            self.block.position.x = (self.block.position.x as i64 + x) as usize;
+            self.block.position.y = (self.block.position.y as i64 + y) as usize;
+        }
+    }
+    
+    fn fall_down(&mut self) {
+        if !self.will_colide(0, -1) {
+            self.block.position.y -= 1;
+        } else {
+            for (i, j) in &self.block.shape {
+                self.map[[self.block.position.x + i, self.block.position.y + j]] = b'#';
+                self.max_height = std::cmp::max(self.max_height, self.block.position.y + j);
+            }
+            self.next_block();
+        }
+    }
+
+    //distance from the top of the tower to the highest rock in each column
+    fn surface(&self) -> Vec<usize> {
+        (1..8).map(|x| {
+            (0..64)
+                .find(|depth| *depth <= self.max_height && self.map[[x, self.max_height - depth]] != b'.')
+                .unwrap_or(64)
+        }).collect()
+    }
+}
+
+
+
+
+pub fn part1(input: &str) -> i64 {
+    let mut chamber = Chamber::new(2022 * 4 + 8);
+    let moves = input.trim().chars().collect::<VecDeque<_>>();
+    let mut i = 0;
+    while chamber.block_no < 2022 {
+        let move_no = i % moves.len();
+        chamber.next_move(moves[move_no]);
+        chamber.fall_down();
+        i += 1;
+    }
+    chamber.max_height as i64
+}
+
+
+pub fn part2(input: &str) -> i64 {
+    let mut chamber = Chamber::new(1000000);
+    let moves = input.trim().chars().collect::<VecDeque<_>>();
+    let mut i = 0;
+    let mut seen: HashMap<(usize, usize, Vec<usize>), (usize, usize)> = HashMap::new();
+    let mut max_height_adj = 0;
+    while chamber.block_no < 1000000000000 {
+        let block_no = chamber.block_no;
+        let move_no = i % moves.len();
+        chamber.next_move(moves[move_no]);
+        chamber.fall_down();
+        i += 1;
+        //once the same block lands on the same surface at the same point in the moves, skip ahead by whole cycles
+        if chamber.block_no != block_no && max_height_adj == 0 {
+            let key = (chamber.block_no % 5, i % moves.len(), chamber.surface());
+            if let Some((previous_no, previous_height)) = seen.insert(key, (chamber.block_no, chamber.max_height)) {
+                let cycle_length = chamber.block_no - previous_no;
+                let cycles = (1000000000000 - chamber.block_no) / cycle_length;
+                chamber.block_no += cycles * cycle_length;
+                max_height_adj = cycles * (chamber.max_height - previous_height);
+            }
+        }
+    }
+    (chamber.max_height + max_height_adj) as i64
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = String;
+
+    fn year(&self) -> u32 {
+        2022
+    }
+
+    fn day(&self) -> u32 {
+        17
+    }
+
+    fn parse(&self, input: &str) -> String {
+        input.to_string()
+    }
+
+    fn parses(&self) -> bool {
+        false
+    }
+
+    fn part1(&self, input: &String) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(&self, input: &String) -> Option<Answer> {
+        Some(part2(input).into())
+    }
+}
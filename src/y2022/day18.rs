@@ -0,0 +1,177 @@
+use std::str::FromStr;
+use crate::answer::Answer;
+use crate::solution::Solution;
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_input() ->  &'static str {
+"2,2,2
+1,2,2
+3,2,2
+2,1,2
+2,3,2
+2,2,1
+2,2,3
+2,2,4
+2,2,6
+1,2,5
+3,2,5
+2,1,5
+2,3,5"
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(test_input()), 64);
+    }
+    
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(test_input()), 58);
+    }
+   
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Content {
+    Air,
+    Lava,
+    Vacuum
+}
+
+#[derive(Clone)]
+pub struct Grid {
+    // 0 = air, 1 = lava, 2 = vacuum
+    grid: [[[Content; 24]; 24]; 24],
+}
+
+impl FromStr for Grid {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut grid = [[[Content::Air; 24]; 24]; 24];
+        s.lines().map(|l| 
+            l.trim().split(',').collect::<Vec<_>>()
+        ).for_each(|v| {
+            let x = v[0].parse::<usize>().unwrap();
+            let y = v[1].parse::<usize>().unwrap();
+            let z = v[2].parse::<usize>().unwrap();
+            grid[x+1][y+1][z+1] = Content::Lava;
+        });
+        Ok(Grid { grid })
+    }
+}
+
+impl Grid {
+    fn sides(&self) -> i64 {
+        let mut sides = 0;
+        assert_eq!(self.grid.len(), 24);
+        assert_eq!(self.grid[0].len(), 24);
+        assert_eq!(self.grid[0][0].len(), 24);
+        for x in 1..23 {
+            for y in 1..23 {
+                for z in 1..23 {
+                    if self.grid[x][y][z] == Content::Lava {
+                        if self.grid[x+1][y][z] == Content::Air { sides += 1; }
+                        if self.grid[x-1][y][z] == Content::Air { sides += 1; }
+                        if self.grid[x][y+1][z] == Content::Air { sides += 1; }
+                        if self.grid[x][y-1][z] == Content::Air { sides += 1; }
+                        if self.grid[x][y][z+1] == Content::Air { sides += 1; }
+                        if self.grid[x][y][z-1] == Content::Air { sides += 1; }
+                    }
+                }
+            }
+        }
+        sides
+    }
+
+    fn set_vacuum(&mut self) {
+        assert_eq!(self.grid.len(), 24);
+        assert_eq!(self.grid[0].len(), 24);
+        assert_eq!(self.grid[0][0].len(), 24);
+        for x in 1..23 {
+            for y in 1..23 {
+                for z in 1..23 {
+                    if self.grid[x][y][z] == Content::Air {
+                        self.grid[x][y][z] = Content::Vacuum;
+                    }
+                }
+            }
+        }
+    }
+
+    fn air_expansion(&mut self) {
+        let mut changed = true;
+        assert_eq!(self.grid.len(), 24);
+        assert_eq!(self.grid[0].len(), 24);
+        assert_eq!(self.grid[0][0].len(), 24);
+        while changed {
+            changed = false;
+            for x in 1..23 {
+                for y in 1..23 {
+                    for z in 1..23 {
+                        if self.grid[x][y][z] == Content::Vacuum &&
+                            ( self.grid[x+1][y][z] == Content::Air ||
+                                self.grid[x-1][y][z] == Content::Air ||
+                                self.grid[x][y+1][z] == Content::Air ||
+                                self.grid[x][y-1][z] == Content::Air || 
+                                self.grid[x][y][z+1] == Content::Air || 
+                                self.grid[x][y][z-1] == Content::Air ){
+                            changed = true;
+                            self.grid[x][y][z] = Content::Air;
+                         }
+                    }
+                }
+            }
+        }
+    }
+}
+
+
+
+pub fn part1(input: &str) -> i64 {
+    let grid: Grid = input.parse().unwrap();
+    //print size of grid
+    grid.sides()
+}
+
+
+fn exterior_sides(mut grid: Grid) -> i64 {
+    grid.set_vacuum();
+    grid.air_expansion();
+    grid.sides()
+}
+
+pub fn part2(input: &str) -> i64 {
+    let grid: Grid = input.parse().unwrap();
+    exterior_sides(grid)
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = Grid;
+
+    fn year(&self) -> u32 {
+        2022
+    }
+
+    fn day(&self) -> u32 {
+        18
+    }
+
+    fn parse(&self, input: &str) -> Grid {
+        input.parse().unwrap()
+    }
+
+    fn part1(&self, input: &Grid) -> Answer {
+        input.sides().into()
+    }
+
+    fn part2(&self, input: &Grid) -> Option<Answer> {
+        Some(exterior_sides(input.clone()).into())
+    }
+}
@@ -0,0 +1,54 @@
+use crate::answer::Answer;
+use crate::solution::Solution;
+
+//define type of game where each player get a char
+
+pub fn part1(input_text: &str) -> usize {
+    let mut score = 0;
+    let games = input_text.lines();
+    for game in games {
+        match game {
+            "A X" => score += 3,
+            "A Y" => score += 1 + 3,
+            "A Z" => score += 2 + 6,
+            "B X" => score += 1,
+            "B Y" => score += 2 + 3,
+            "B Z" => score += 3 + 6,
+            "C X" => score += 2,
+            "C Y" => score += 3 + 3,
+            "C Z" => score += 1 + 6,
+            _ => (),
+        }
+    }
+    score
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = String;
+
+    fn year(&self) -> u32 {
+        2022
+    }
+
+    fn day(&self) -> u32 {
+        2
+    }
+
+    fn parts(&self) -> u32 {
+        1
+    }
+
+    fn parse(&self, input: &str) -> String {
+        input.to_string()
+    }
+
+    fn parses(&self) -> bool {
+        false
+    }
+
+    fn part1(&self, input: &String) -> Answer {
+        part1(input).into()
+    }
+}
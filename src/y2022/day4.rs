@@ -0,0 +1,98 @@
+use std::str::FromStr;
+use crate::answer::Answer;
+use crate::solution::Solution;
+
+#[derive(Debug, Clone)]
+pub struct Pair {
+    left: Assignment,
+    right: Assignment
+}
+
+#[derive(Debug, Clone)]
+struct Assignment {
+    min: usize,
+    max: usize,
+}
+
+impl FromStr for Pair {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.split(",");
+        let left: Assignment = parts.next().unwrap().parse().unwrap();
+        let right: Assignment = parts.next().unwrap().parse().unwrap();
+        Ok(Pair{left, right})
+    }
+}
+
+impl FromStr for Assignment {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut split = s.split('-');
+        let min = split.next().unwrap().parse::<usize>().unwrap();
+        let max = split.next().unwrap().parse::<usize>().unwrap();
+        Ok(Assignment {min, max})
+    }
+}
+
+impl Assignment {
+    fn contains(&self, other: &Assignment) -> bool {
+        self.min <= other.min && self.max >= other.max
+    }
+    fn overlap(&self, other: &Assignment) -> bool {
+        self.min <= other.max && self.max >= other.min
+    }
+}
+
+fn parse(input: &str) -> Vec<Pair> {
+    input
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(|s| s.parse::<Pair>().unwrap())
+        .collect()
+}
+
+pub fn part1(input: &str) -> usize {
+    let mut contain_count = 0;
+    for pair in parse(input) {
+        if pair.left.contains(&pair.right) || pair.right.contains(&pair.left) {
+            contain_count += 1;
+        }
+    }
+    contain_count
+}
+
+pub fn part2(input: &str) -> usize {
+    let mut overlap_count = 0;
+    for pair in parse(input) {
+        if pair.left.overlap(&pair.right) {
+            overlap_count += 1;
+        }
+    }
+    overlap_count
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Vec<Pair>;
+
+    fn year(&self) -> u32 {
+        2022
+    }
+
+    fn day(&self) -> u32 {
+        4
+    }
+
+    fn parse(&self, input: &str) -> Vec<Pair> {
+        parse(input)
+    }
+
+    fn part1(&self, input: &Vec<Pair>) -> Answer {
+        input.iter().filter(|pair| pair.left.contains(&pair.right) || pair.right.contains(&pair.left)).count().into()
+    }
+
+    fn part2(&self, input: &Vec<Pair>) -> Option<Answer> {
+        Some(input.iter().filter(|pair| pair.left.overlap(&pair.right)).count().into())
+    }
+}
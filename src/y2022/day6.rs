@@ -0,0 +1,54 @@
+use itertools::Itertools;
+use crate::answer::Answer;
+use crate::solution::Solution;
+
+fn all_unique(s: &[u8]) -> bool {
+    s.iter().unique().count() == s.len()
+}
+
+fn marker_position(s: &[u8], n: &usize) -> usize {
+    for i in 0..s.len()-(n-1) {
+        if all_unique(&s[i..i+n]) {
+            return i+n;
+        }
+    }
+    panic!("Could not find marker");
+}
+
+pub fn part1(input: &str) -> usize {
+    marker_position(input.trim().as_bytes(), &4)
+}
+
+pub fn part2(input: &str) -> usize {
+    marker_position(input.trim().as_bytes(), &14)
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = String;
+
+    fn year(&self) -> u32 {
+        2022
+    }
+
+    fn day(&self) -> u32 {
+        6
+    }
+
+    fn parse(&self, input: &str) -> String {
+        input.to_string()
+    }
+
+    fn parses(&self) -> bool {
+        false
+    }
+
+    fn part1(&self, input: &String) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(&self, input: &String) -> Option<Answer> {
+        Some(part2(input).into())
+    }
+}
@@ -0,0 +1,49 @@
+use crate::solution::DynSolution;
+
+pub mod day1;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+
+pub fn solutions() -> Vec<&'static dyn DynSolution> {
+    vec![
+        &day1::Day1,
+        &day2::Day2,
+        &day3::Day3,
+        &day4::Day4,
+        &day5::Day5,
+        &day6::Day6,
+        &day7::Day7,
+        &day8::Day8,
+        &day9::Day9,
+        &day10::Day10,
+        &day11::Day11,
+        &day12::Day12,
+        &day13::Day13,
+        &day14::Day14,
+        &day15::Day15,
+        &day16::Day16,
+        &day17::Day17,
+        &day18::Day18,
+        &day19::Day19,
+        &day20::Day20,
+        &day21::Day21,
+    ]
+}
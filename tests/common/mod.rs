@@ -0,0 +1,149 @@
+#![allow(dead_code)]
+
+use std::collections::HashMap;
+use std::fs;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::path::PathBuf;
+use std::process::{Command, Output};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Instant;
+
+//a request as the stand-in server saw it
+#[derive(Debug, Clone)]
+pub struct Request {
+    pub method: String,
+    pub path: String,
+    pub headers: HashMap<String, String>,
+    pub body: String,
+    pub at: Instant,
+}
+
+#[derive(Clone)]
+struct Route {
+    method: String,
+    path: String,
+    status: u16,
+    body: String,
+}
+
+//local stand-in for adventofcode.com, serving canned pages and recording what was asked of it
+pub struct Server {
+    pub url: String,
+    routes: Arc<Mutex<Vec<Route>>>,
+    requests: Arc<Mutex<Vec<Request>>>,
+}
+
+pub fn page(name: &str) -> String {
+    fs::read_to_string(format!("tests/pages/{}", name)).unwrap()
+}
+
+fn read_request(stream: &mut TcpStream) -> Request {
+    let mut reader = BufReader::new(stream);
+    let mut line = String::new();
+    reader.read_line(&mut line).unwrap();
+    let mut parts = line.split_whitespace();
+    let method = parts.next().unwrap_or_default().to_string();
+    let path = parts.next().unwrap_or_default().to_string();
+    let mut headers = HashMap::new();
+    loop {
+        let mut header = String::new();
+        reader.read_line(&mut header).unwrap();
+        if header.trim().is_empty() {
+            break;
+        }
+        if let Some((name, value)) = header.split_once(':') {
+            headers.insert(name.trim().to_lowercase(), value.trim().to_string());
+        }
+    }
+    let length = headers.get("content-length").map(|l| l.parse().unwrap()).unwrap_or(0);
+    let mut body = vec![0; length];
+    reader.read_exact(&mut body).unwrap();
+    Request { method, path, headers, body: String::from_utf8(body).unwrap(), at: Instant::now() }
+}
+
+impl Server {
+    pub fn start() -> Server {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let routes: Arc<Mutex<Vec<Route>>> = Arc::new(Mutex::new(Vec::new()));
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let (server_routes, server_requests) = (routes.clone(), requests.clone());
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let request = read_request(&mut stream);
+                let route = server_routes.lock().unwrap().iter()
+                    .find(|r| r.method == request.method && r.path == request.path)
+                    .cloned()
+                    .unwrap_or(Route { method: request.method.clone(), path: request.path.clone(), status: 404, body: page("not_found.html") });
+                server_requests.lock().unwrap().push(request);
+                let response = format!(
+                    "HTTP/1.1 {} Canned\r\nContent-Type: text/html\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    route.status, route.body.len(), route.body
+                );
+                stream.write_all(response.as_bytes()).unwrap();
+            }
+        });
+        Server { url, routes, requests }
+    }
+
+    pub fn route(&self, method: &str, path: &str, status: u16, body: &str) -> &Server {
+        self.routes.lock().unwrap().push(Route { method: method.to_string(), path: path.to_string(), status, body: body.to_string() });
+        self
+    }
+
+    pub fn requests(&self) -> Vec<Request> {
+        self.requests.lock().unwrap().clone()
+    }
+
+    pub fn requests_to(&self, path: &str) -> Vec<Request> {
+        self.requests().into_iter().filter(|r| r.path == path).collect()
+    }
+}
+
+//an empty working directory for one run of the binary
+//serves 2022 day 1's input and its puzzle with both parts
+pub fn day1_server() -> Server {
+    let server = Server::start();
+    server
+        .route("GET", "/2022/day/1/input", 200, &page("input_2022_1.txt"))
+        .route("GET", "/2022/day/1", 200, &page("puzzle_2022_1.html"));
+    server
+}
+
+pub fn workdir() -> PathBuf {
+    static COUNT: AtomicUsize = AtomicUsize::new(0);
+    let dir = std::env::temp_dir().join(format!("aoc-test-{}-{}", std::process::id(), COUNT.fetch_add(1, Ordering::SeqCst)));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+//the binary pointed at the stand-in server, with its own user config, for tests that change its environment
+pub fn command(dir: &PathBuf, server: &Server, args: &[&str]) -> Command {
+    let mut command = Command::new(env!("CARGO_BIN_EXE_aoc"));
+    command
+        .args(args)
+        .current_dir(dir)
+        .env("AOC_BASE_URL", &server.url)
+        .env("AOC_COOKIE", "test-session")
+        .env("AOC_MIN_GAP_SECS", "0")
+        .env("AOC_CONFIG_DIR", dir.join(".config"))
+        .env_remove("AOC_CONTACT");
+    command
+}
+
+pub fn aoc(dir: &PathBuf, server: &Server, args: &[&str]) -> Output {
+    command(dir, server, args).output().unwrap()
+}
+
+pub fn stdout(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stdout).to_string()
+}
+
+pub fn stderr(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stderr).to_string()
+}
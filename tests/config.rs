@@ -0,0 +1,179 @@
+mod common;
+
+use std::fs;
+use std::io::Write;
+use std::process::Stdio;
+use common::{aoc, command, day1_server, page, stderr, stdout, workdir, Server};
+
+#[test]
+fn project_config_sets_directories_and_default_year() {
+    let server = day1_server();
+    let dir = workdir();
+    fs::write(dir.join("aoc.toml"), "year = 2022\ninput_dir = \"data/in\"\npuzzle_dir = \"data/puzzles\"\n").unwrap();
+    let output = aoc(&dir, &server, &["pull", "1"]);
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert!(dir.join("data/in/2022_1.txt").exists());
+    assert!(dir.join("data/puzzles/2022_1.md").exists());
+    assert!(!dir.join("inputs").exists());
+
+    //directories are relative to aoc.toml, not to where the command runs
+    let sub = dir.join("src");
+    fs::create_dir_all(&sub).unwrap();
+    let output = aoc(&sub, &server, &["run", "1"]);
+    assert_eq!(stdout(&output), "Part 1: 24000\nPart 2: 45000\n", "{}", stderr(&output));
+}
+
+#[test]
+fn command_line_overrides_project_config() {
+    let server = day1_server();
+    let dir = workdir();
+    fs::write(dir.join("aoc.toml"), "year = 2015\nformat = \"json\"\n").unwrap();
+    aoc(&dir, &server, &["pull", "--year", "2022", "1", "--input-dir", "elsewhere"]);
+    assert!(dir.join("elsewhere/2022_1.txt").exists());
+
+    let output = aoc(&dir, &server, &["run", "2022", "1", "--input-dir", "elsewhere"]);
+    let json: serde_json::Value = serde_json::from_str(&stdout(&output)).unwrap();
+    assert_eq!(json["parts"][1]["answer"], "45000");
+    let output = aoc(&dir, &server, &["run", "2022", "1", "--input-dir", "elsewhere", "--format", "text"]);
+    assert!(stdout(&output).starts_with("Part 1: 24000"));
+
+    fs::write(dir.join("aoc.toml"), "answers_file = \"broken.toml\"\n").unwrap();
+    fs::write(dir.join("broken.toml"), "[[default]\n").unwrap();
+    let output = aoc(&dir, &server, &["verify"]);
+    assert_eq!(output.status.code(), Some(8));
+    let output = aoc(&dir, &server, &["verify", "--answers-file", "other.toml"]);
+    assert!(stderr(&output).contains("No known answers for profile default in other.toml"), "{}", stderr(&output));
+    assert!(output.status.success());
+}
+
+#[test]
+fn run_all_ignores_the_default_year() {
+    let server = Server::start();
+    let dir = workdir();
+    fs::write(dir.join("aoc.toml"), "year = 2022\n").unwrap();
+    let text = stdout(&aoc(&dir, &server, &["run", "--all"]));
+    assert!(text.lines().any(|l| l.starts_with("2015")), "{}", text);
+    let text = stdout(&aoc(&dir, &server, &["run", "2022", "--all"]));
+    assert!(!text.lines().any(|l| l.starts_with("2015")), "{}", text);
+}
+
+#[test]
+fn a_lone_day_needs_a_default_year() {
+    let server = day1_server();
+    let dir = workdir();
+    let output = command(&dir, &server, &["pull", "1"]).output().unwrap();
+    assert!(stderr(&output).contains("No year given"));
+    assert_eq!(output.status.code(), Some(9));
+    assert!(server.requests().is_empty());
+}
+
+#[test]
+fn run_reads_another_input_from_a_file_or_stdin() {
+    let server = Server::start();
+    let dir = workdir();
+    let example = fs::read_to_string("fixtures/2022_1/part1.txt").unwrap();
+    fs::write(dir.join("theirs.txt"), &example).unwrap();
+    let output = aoc(&dir, &server, &["run", "2022", "1", "--input", "theirs.txt"]);
+    assert_eq!(stdout(&output), "Part 1: 24000\nPart 2: 45000\n", "{}", stderr(&output));
+
+    let mut child = command(&dir, &server, &["run", "2022", "1", "--input", "-"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child.stdin.take().unwrap().write_all(example.as_bytes()).unwrap();
+    let output = child.wait_with_output().unwrap();
+    assert_eq!(stdout(&output), "Part 1: 24000\nPart 2: 45000\n");
+
+    //2015 day 1 has no part 2 answer when the basement is never reached
+    fs::write(dir.join("upstairs.txt"), "(()(").unwrap();
+    let output = aoc(&dir, &server, &["run", "2015", "1", "--input", "upstairs.txt"]);
+    assert_eq!(stdout(&output), "Part 1: 2\n", "{}", stderr(&output));
+    assert!(output.status.success());
+
+    let output = aoc(&dir, &server, &["run", "2022", "1", "--input", "missing.txt"]);
+    assert!(stderr(&output).contains("Could not read input missing.txt"));
+    assert_eq!(output.status.code(), Some(11));
+
+    let output = aoc(&dir, &server, &["run", "2021", "25"]);
+    assert!(stderr(&output).contains("No solution for 2021 day 25"));
+    assert_eq!(output.status.code(), Some(10));
+}
+
+#[test]
+fn an_unreadable_project_config_is_an_error() {
+    let server = day1_server();
+    let dir = workdir();
+    fs::write(dir.join("aoc.toml"), "year = \"2022\"\n").unwrap();
+    let output = aoc(&dir, &server, &["pull", "2022", "1"]);
+    assert!(stderr(&output).contains("Could not read aoc.toml"), "{}", stderr(&output));
+    assert_eq!(output.status.code(), Some(13));
+    assert!(server.requests().is_empty());
+}
+
+#[test]
+fn fixtures_and_submit_work_from_anywhere_in_the_project() {
+    let server = day1_server();
+    server.route("POST", "/2022/day/1/answer", 200, &page("answer_too_high.html"));
+    let dir = workdir();
+    fs::write(dir.join("aoc.toml"), "year = 2022\n").unwrap();
+    let sub = dir.join("src");
+    fs::create_dir_all(&sub).unwrap();
+    let output = aoc(&sub, &server, &["examples", "1"]);
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert!(dir.join("fixtures/2022_1/part1.answer").exists());
+    assert!(!sub.join("fixtures").exists());
+
+    let text = stdout(&aoc(&sub, &server, &["list"]));
+    let tests = text.lines().skip_while(|l| !l.starts_with("2022")).find(|l| l.starts_with("tests")).unwrap();
+    assert_eq!(tests.split_whitespace().nth(1), Some("#"), "{}", text);
+
+    let output = aoc(&sub, &server, &["submit", "2022", "1", "2", "999999"]);
+    assert!(stderr(&output).contains("too high"), "{}", stderr(&output));
+    assert_eq!(server.requests_to("/2022/day/1/answer")[0].body, "level=2&answer=999999");
+}
+
+#[test]
+fn new_scaffolds_the_day_from_anywhere_in_the_project() {
+    let server = Server::start();
+    let dir = workdir();
+    fs::write(dir.join("aoc.toml"), "").unwrap();
+    fs::write(dir.join("Cargo.toml"), "[package]\nname = \"aoc\"\n").unwrap();
+    fs::create_dir_all(dir.join("src/bin")).unwrap();
+    fs::write(dir.join("src/lib.rs"), "pub mod y2022;\n").unwrap();
+    fs::write(dir.join("src/solution.rs"), "    solutions.extend(crate::y2022::solutions());\n").unwrap();
+    let output = aoc(&dir.join("src"), &server, &["new", "2023", "1"]);
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert!(dir.join("src/y2023/day1.rs").exists());
+    assert!(dir.join("src/bin/2023_1.rs").exists());
+    assert!(fs::read_to_string(dir.join("Cargo.toml")).unwrap().contains("name = \"2023_1\""));
+    assert!(fs::read_to_string(dir.join("src/lib.rs")).unwrap().contains("#[allow(clippy::items_after_test_module)]\npub mod y2023;"));
+
+    let output = aoc(&dir, &server, &["new", "2023", "1"]);
+    assert!(stderr(&output).contains("already exists"), "{}", stderr(&output));
+    assert_eq!(output.status.code(), Some(8));
+    let output = aoc(&dir, &server, &["new", "2023", "26"]);
+    assert_eq!(output.status.code(), Some(14), "{}", stderr(&output));
+
+    fs::remove_file(dir.join("Cargo.toml")).unwrap();
+    let output = aoc(&dir, &server, &["new", "2023", "2"]);
+    assert!(stderr(&output).contains("Could not read Cargo.toml"), "{}", stderr(&output));
+    assert_eq!(output.status.code(), Some(8));
+}
+
+#[test]
+fn bench_needs_the_baseline_it_compares_with() {
+    let server = Server::start();
+    let dir = workdir();
+    fs::create_dir_all(dir.join("inputs")).unwrap();
+    fs::copy("fixtures/2022_4/part1.txt", dir.join("inputs/2022_4.txt")).unwrap();
+    let output = aoc(&dir, &server, &["bench", "2022", "4", "--baseline", "old", "--quick"]);
+    assert!(stderr(&output).contains("No baseline old for 2022 day 4"), "{}", stderr(&output));
+    assert_eq!(output.status.code(), Some(16));
+
+    fs::write(dir.join("aoc.toml"), "bench_dir = \"benches\"\n").unwrap();
+    let output = aoc(&dir, &server, &["bench", "2022", "4", "--quick", "--bench-dir", "elsewhere"]);
+    assert!(stdout(&output).contains("Saved as baseline base in elsewhere/2022_4"), "{}", stderr(&output));
+    assert!(dir.join("elsewhere/2022_4/part1/base/estimates.json").exists());
+    assert!(!dir.join("benches").exists());
+}
@@ -0,0 +1,48 @@
+use std::fs;
+use aoc::answer::Answer;
+use aoc::solution;
+
+//runs every fixture written by `aoc examples` against the registered solution
+#[test]
+fn fixtures_match_solutions() {
+    let mut failures = Vec::new();
+    let mut checked = 0;
+    for entry in fs::read_dir("fixtures").unwrap() {
+        let dir = entry.unwrap().path();
+        let name = dir.file_name().unwrap().to_str().unwrap().to_string();
+        let (year, day) = name.split_once('_').unwrap();
+        let (year, day) = (year.parse::<u32>().unwrap(), day.parse::<u32>().unwrap());
+        let solution = match solution::find(year, day) {
+            Some(solution) => solution,
+            None => continue,
+        };
+        for part in 1..=solution.parts() {
+            let input = match fs::read_to_string(dir.join(format!("part{}.txt", part))) {
+                Ok(input) => input,
+                Err(_) => continue,
+            };
+            let expected = Answer::from(fs::read_to_string(dir.join(format!("part{}.answer", part))).unwrap());
+            let parsed = solution.parse(&input);
+            let answer = match solution.part(part, parsed.as_ref()) {
+                Some(answer) => answer,
+                None => {
+                    failures.push(format!("{} part {}: not solved", name, part));
+                    continue;
+                }
+            };
+            checked += 1;
+            if answer != expected {
+                failures.push(format!("{} part {}: expected {}, got {}", name, part, expected, answer));
+            }
+            //alternative implementations have to agree with the example too
+            for (_, alternative) in solution.alternatives().into_iter().filter(|(p, _)| *p == part) {
+                let answer = solution.alternative(part, alternative, parsed.as_ref()).unwrap();
+                if answer != expected {
+                    failures.push(format!("{} part {} ({}): expected {}, got {}", name, part, alternative, expected, answer));
+                }
+            }
+        }
+    }
+    assert!(checked > 0, "no fixtures found");
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
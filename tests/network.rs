@@ -0,0 +1,508 @@
+mod common;
+
+use std::fs;
+use std::net::TcpListener;
+use std::time::Duration;
+use common::{aoc, command, day1_server, page, stderr, stdout, workdir, Server};
+
+#[test]
+fn pull_saves_input_puzzle_and_examples() {
+    let server = day1_server();
+    let dir = workdir();
+    let output = aoc(&dir, &server, &["pull", "2022", "1"]);
+    assert!(output.status.success(), "{}", stderr(&output));
+
+    assert_eq!(fs::read_to_string(dir.join("inputs/2022_1.txt")).unwrap(), page("input_2022_1.txt"));
+    let puzzle = fs::read_to_string(dir.join("puzzles/2022_1.md")).unwrap();
+    assert!(puzzle.starts_with("## --- Day 1: Calorie Counting ---"));
+    assert!(puzzle.contains("## --- Part Two ---"));
+    assert!(puzzle.contains(&format!("[magical energy]({}/2018/day/25)", server.url)));
+    assert_eq!(fs::read_to_string(dir.join("fixtures/2022_1/part1.answer")).unwrap(), "24000\n");
+    assert_eq!(fs::read_to_string(dir.join("fixtures/2022_1/part2.answer")).unwrap(), "45000\n");
+
+    let session = &server.requests_to("/2022/day/1/input")[0].headers["cookie"];
+    assert_eq!(session, "session=test-session");
+}
+
+#[test]
+fn pull_skips_saved_input_unless_forced() {
+    let server = day1_server();
+    let dir = workdir();
+    aoc(&dir, &server, &["pull", "2022", "1"]);
+    let output = aoc(&dir, &server, &["pull", "2022", "1"]);
+    assert!(stdout(&output).contains("inputs/2022_1.txt already exists"));
+    assert!(stdout(&output).contains("puzzles/2022_1.md already has both parts"));
+    assert_eq!(server.requests_to("/2022/day/1/input").len(), 1);
+    assert_eq!(server.requests_to("/2022/day/1").len(), 1);
+
+    aoc(&dir, &server, &["pull", "2022", "1", "--force"]);
+    assert_eq!(server.requests_to("/2022/day/1/input").len(), 2);
+    assert_eq!(server.requests_to("/2022/day/1").len(), 2);
+}
+
+#[test]
+fn pull_keeps_fixtures_unless_examples_is_forced() {
+    let server = day1_server();
+    server.route("GET", "/2022/day/2", 200, "<main><article><h2>--- Day 2 ---</h2><p>No example.</p></article></main>");
+    server.route("GET", "/2022/day/2/input", 200, "A Y\n");
+    let dir = workdir();
+    aoc(&dir, &server, &["pull", "2022", "2"]);
+    assert!(!dir.join("fixtures/2022_2").exists());
+
+    aoc(&dir, &server, &["pull", "2022", "1"]);
+    fs::write(dir.join("fixtures/2022_1/part1.answer"), "fixed by hand\n").unwrap();
+    aoc(&dir, &server, &["pull", "2022", "1", "--force"]);
+    assert_eq!(fs::read_to_string(dir.join("fixtures/2022_1/part1.answer")).unwrap(), "fixed by hand\n");
+    aoc(&dir, &server, &["examples", "2022", "1", "--force"]);
+    assert_eq!(fs::read_to_string(dir.join("fixtures/2022_1/part1.answer")).unwrap(), "24000\n");
+}
+
+#[test]
+fn pull_identifies_itself() {
+    let server = day1_server();
+    let dir = workdir();
+    command(&dir, &server, &["pull", "2022", "1"]).env("AOC_CONTACT", "someone@example.com").output().unwrap();
+    let user_agent = &server.requests_to("/2022/day/1/input")[0].headers["user-agent"];
+    assert!(user_agent.starts_with("aoc/"));
+    assert!(user_agent.ends_with("(by someone@example.com)"));
+}
+
+#[test]
+fn pull_fills_the_input_left_empty_by_new() {
+    let server = Server::start();
+    server
+        .route("GET", "/2022/day/22/input", 200, "input 22\n")
+        .route("GET", "/2022/day/22", 200, &page("puzzle_2022_1.html"));
+    let dir = workdir();
+    fs::write(dir.join("aoc.toml"), "").unwrap();
+    fs::write(dir.join("Cargo.toml"), "[package]\nname = \"aoc\"\n").unwrap();
+    fs::create_dir_all(dir.join("src/bin")).unwrap();
+    fs::write(dir.join("src/lib.rs"), "").unwrap();
+    fs::write(dir.join("src/solution.rs"), "").unwrap();
+    let output = aoc(&dir, &server, &["new", "2022", "22"]);
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert_eq!(fs::read_to_string(dir.join("inputs/2022_22.txt")).unwrap(), "");
+    let day22 = |text: String| {
+        let input = text.lines().skip_while(|l| !l.starts_with("2022")).find(|l| l.starts_with("input")).unwrap();
+        input.split_whitespace().nth(22).unwrap().to_string()
+    };
+    assert_eq!(day22(stdout(&aoc(&dir, &server, &["list"]))), ".");
+
+    let output = aoc(&dir, &server, &["pull", "2022", "22"]);
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert_eq!(fs::read_to_string(dir.join("inputs/2022_22.txt")).unwrap(), "input 22\n");
+    assert_eq!(day22(stdout(&aoc(&dir, &server, &["list"]))), "#");
+}
+
+#[test]
+fn pull_reuses_cached_pages() {
+    let server = day1_server();
+    let dir = workdir();
+    aoc(&dir, &server, &["pull", "2022", "1"]);
+    fs::remove_dir_all(dir.join("inputs")).unwrap();
+    fs::remove_dir_all(dir.join("puzzles")).unwrap();
+    let output = aoc(&dir, &server, &["pull", "2022", "1"]);
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert_eq!(fs::read_to_string(dir.join("inputs/2022_1.txt")).unwrap(), page("input_2022_1.txt"));
+    assert!(dir.join("puzzles/2022_1.md").exists());
+    assert_eq!(server.requests().len(), 2);
+}
+
+#[test]
+fn pull_spaces_out_requests_across_runs() {
+    let server = day1_server();
+    server.route("GET", "/2022/day/2/input", 200, "A Y\n");
+    let dir = workdir();
+    command(&dir, &server, &["pull", "2022", "1"]).env("AOC_MIN_GAP_SECS", "0.5").output().unwrap();
+    command(&dir, &server, &["pull", "2022", "2"]).env("AOC_MIN_GAP_SECS", "0.5").output().unwrap();
+    let requests = server.requests();
+    assert_eq!(requests.len(), 4);
+    for pair in requests.windows(2) {
+        assert!(pair[1].at - pair[0].at >= Duration::from_millis(450));
+    }
+}
+
+#[test]
+fn pull_appends_part2_once_unlocked() {
+    let server = Server::start();
+    server
+        .route("GET", "/2022/day/1/input", 200, &page("input_2022_1.txt"))
+        .route("GET", "/2022/day/1", 200, &page("puzzle_2022_1_part1.html"));
+    let dir = workdir();
+    aoc(&dir, &server, &["pull", "2022", "1"]);
+    let puzzle = fs::read_to_string(dir.join("puzzles/2022_1.md")).unwrap();
+    assert!(!puzzle.contains("--- Part Two ---"));
+
+    let unlocked = day1_server();
+    let output = aoc(&dir, &unlocked, &["pull", "2022", "1"]);
+    assert!(stdout(&output).contains("Added part 2 to puzzles/2022_1.md"));
+    let puzzle = fs::read_to_string(dir.join("puzzles/2022_1.md")).unwrap();
+    assert!(puzzle.contains("--- Part Two ---"));
+    assert!(unlocked.requests_to("/2022/day/1/input").is_empty());
+}
+
+#[test]
+fn pull_does_not_save_error_pages() {
+    let server = Server::start();
+    server
+        .route("GET", "/2022/day/2/input", 400, &page("input_logged_out.txt"));
+    let dir = workdir();
+    let output = aoc(&dir, &server, &["pull", "2022", "2"]);
+    assert_eq!(output.status.code(), Some(4));
+    assert!(stderr(&output).contains("session cookie was not accepted"));
+    assert!(!dir.join("inputs/2022_2.txt").exists());
+
+    let output = aoc(&dir, &server, &["pull", "2022", "25"]);
+    assert_eq!(output.status.code(), Some(5));
+    assert!(stderr(&output).contains("not available yet"));
+    assert!(!dir.join("inputs/2022_25.txt").exists());
+}
+
+#[test]
+fn pull_waits_for_locked_days() {
+    let server = Server::start();
+    let dir = workdir();
+    let output = aoc(&dir, &server, &["pull", "2099", "1"]);
+    assert_eq!(output.status.code(), Some(5));
+    assert!(stderr(&output).contains("2099 day 1 unlocks in"));
+    assert!(stderr(&output).contains("--wait"));
+    assert!(server.requests().is_empty());
+}
+
+#[test]
+fn pull_reports_server_errors() {
+    let server = Server::start();
+    server
+        .route("GET", "/2022/day/2/input", 200, "1\n2\n")
+        .route("GET", "/2022/day/2", 500, &page("server_error.html"));
+    let dir = workdir();
+    let output = aoc(&dir, &server, &["pull", "2022", "2"]);
+    assert_eq!(output.status.code(), Some(6));
+    assert!(stderr(&output).contains("error (500)"));
+    assert!(!dir.join("puzzles/2022_2.md").exists());
+}
+
+#[test]
+fn pull_all_fetches_every_day_and_summarises() {
+    let server = Server::start();
+    //the first matching route wins
+    server.route("GET", "/2022/day/3/input", 500, &page("server_error.html"));
+    for day in 1..=25 {
+        server
+            .route("GET", &format!("/2022/day/{}/input", day), 200, &format!("input {}\n", day))
+            .route("GET", &format!("/2022/day/{}", day), 200, &page("puzzle_2022_1.html"));
+    }
+    let dir = workdir();
+    fs::create_dir_all(dir.join("inputs")).unwrap();
+    fs::write(dir.join("inputs/2022_2.txt"), "mine\n").unwrap();
+    let output = aoc(&dir, &server, &["pull", "2022", "--all", "--concurrency", "8"]);
+    assert_eq!(output.status.code(), Some(6), "{}", stderr(&output));
+
+    assert_eq!(fs::read_to_string(dir.join("inputs/2022_25.txt")).unwrap(), "input 25\n");
+    assert_eq!(fs::read_to_string(dir.join("inputs/2022_2.txt")).unwrap(), "mine\n");
+    assert!(!dir.join("inputs/2022_3.txt").exists());
+    assert!(dir.join("puzzles/2022_3.md").exists());
+    let text = stdout(&output);
+    let summary: Vec<&str> = text.lines().skip_while(|l| !l.starts_with("day")).collect();
+    assert_eq!(summary.len(), 26);
+    assert_eq!(summary[1].split_whitespace().collect::<Vec<_>>(), vec!["1", "saved", "saved"]);
+    assert_eq!(summary[2].split_whitespace().collect::<Vec<_>>(), vec!["2", "already", "there", "saved"]);
+    assert!(summary[3].contains("error (500)"));
+    assert!(server.requests_to("/2022/day/2/input").is_empty());
+
+    //only what is still missing is fetched again
+    let output = aoc(&dir, &server, &["pull", "2022", "--all"]);
+    assert_eq!(output.status.code(), Some(6));
+    assert_eq!(server.requests_to("/2022/day/1/input").len(), 1);
+    assert_eq!(server.requests_to("/2022/day/1").len(), 1);
+    assert_eq!(server.requests_to("/2022/day/3/input").len(), 2);
+}
+
+#[test]
+fn pull_needs_a_cookie() {
+    let server = day1_server();
+    let dir = workdir();
+    let output = command(&dir, &server, &["pull", "2022", "1"]).env_remove("AOC_COOKIE").output().unwrap();
+    assert_eq!(output.status.code(), Some(3));
+    assert!(stderr(&output).contains("AOC_COOKIE"));
+    assert!(server.requests().is_empty());
+
+    let output = command(&dir, &server, &["pull", "2022", "1"]).env("AOC_COOKIE", "bad\ncookie").output().unwrap();
+    assert_eq!(output.status.code(), Some(4));
+    assert!(server.requests().is_empty());
+}
+
+#[test]
+fn pull_reports_network_failures() {
+    //nothing listens on a port that was just released
+    let port = TcpListener::bind("127.0.0.1:0").unwrap().local_addr().unwrap().port();
+    let server = Server::start();
+    let dir = workdir();
+    let output = command(&dir, &server, &["pull", "2022", "1"])
+        .env("AOC_BASE_URL", format!("http://127.0.0.1:{}", port))
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(7));
+    assert!(stderr(&output).contains("Could not reach the server"));
+}
+
+#[test]
+fn login_checks_and_saves_the_session() {
+    let server = Server::start();
+    server.route("GET", "/", 200, &page("home_logged_in.html"));
+    let dir = workdir();
+    let output = aoc(&dir, &server, &["login", "--profile", "work", "work-session"]);
+    assert!(stdout(&output).contains("Logged in as Ada"), "{}", stderr(&output));
+    assert_eq!(server.requests_to("/")[0].headers["cookie"], "session=work-session");
+
+    let config = dir.join(".config/config.toml");
+    assert!(fs::read_to_string(&config).unwrap().contains("[profiles.work]\nsession = \"work-session\""));
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        assert_eq!(fs::metadata(&config).unwrap().permissions().mode() & 0o777, 0o600);
+    }
+}
+
+#[test]
+fn login_rejects_a_session_the_site_does_not_know() {
+    let server = Server::start();
+    server.route("GET", "/", 200, &page("home_logged_out.html"));
+    let dir = workdir();
+    let output = aoc(&dir, &server, &["login", "expired-session"]);
+    assert_eq!(output.status.code(), Some(4));
+    assert!(!dir.join(".config/config.toml").exists());
+}
+
+#[test]
+fn login_reports_a_malformed_config_instead_of_replacing_it() {
+    let server = Server::start();
+    server.route("GET", "/", 200, &page("home_logged_in.html"));
+    let dir = workdir();
+    fs::create_dir_all(dir.join(".config")).unwrap();
+    fs::write(dir.join(".config/config.toml"), "[profiles.work\n").unwrap();
+    let output = aoc(&dir, &server, &["login", "work-session"]);
+    assert_eq!(output.status.code(), Some(8));
+    assert!(stderr(&output).contains("config.toml"), "{}", stderr(&output));
+    assert_eq!(fs::read_to_string(dir.join(".config/config.toml")).unwrap(), "[profiles.work\n");
+}
+
+#[cfg(unix)]
+#[test]
+fn login_leaves_an_existing_config_dir_alone() {
+    use std::os::unix::fs::PermissionsExt;
+    let server = Server::start();
+    server.route("GET", "/", 200, &page("home_logged_in.html"));
+    let dir = workdir();
+    fs::create_dir_all(dir.join(".config")).unwrap();
+    fs::set_permissions(dir.join(".config"), fs::Permissions::from_mode(0o755)).unwrap();
+    let output = aoc(&dir, &server, &["login", "work-session"]);
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert_eq!(fs::metadata(dir.join(".config")).unwrap().permissions().mode() & 0o777, 0o755);
+}
+
+#[test]
+fn pull_uses_the_profile_session_and_input_folder() {
+    let server = day1_server();
+    server.route("GET", "/", 200, &page("home_logged_in.html"));
+    let dir = workdir();
+    aoc(&dir, &server, &["login", "--profile", "work", "work-session"]);
+    let output = command(&dir, &server, &["pull", "2022", "1", "--profile", "work"]).env_remove("AOC_COOKIE").output().unwrap();
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert_eq!(fs::read_to_string(dir.join("inputs/work/2022_1.txt")).unwrap(), page("input_2022_1.txt"));
+    assert!(!dir.join("inputs/2022_1.txt").exists());
+    assert_eq!(server.requests_to("/2022/day/1/input")[0].headers["cookie"], "session=work-session");
+
+    //AOC_COOKIE only stands in for the default profile
+    let output = command(&dir, &server, &["pull", "2022", "1", "--profile", "home"]).output().unwrap();
+    assert_eq!(output.status.code(), Some(3));
+    assert!(stderr(&output).contains("aoc login"));
+}
+
+#[test]
+fn leaderboard_ranks_members_and_polls_at_most_every_15_minutes() {
+    let server = Server::start();
+    server.route("GET", "/2022/leaderboard/private/view/1234.json", 200, &page("leaderboard.json"));
+    let dir = workdir();
+    let output = aoc(&dir, &server, &["leaderboard", "1234", "--year", "2022"]);
+    assert!(output.status.success(), "{}", stderr(&output));
+    let text = stdout(&output);
+    let ranking: Vec<&str> = text.lines().skip(3).take(3).map(|l| l.split("  ").nth(1).unwrap().trim()).collect();
+    assert_eq!(ranking, vec!["Ada", "(anonymous user #5678)", "Grace"]);
+    assert!(text.contains("Dec 03 05:06:40 (+1d 0h 6m 40s)"));
+
+    aoc(&dir, &server, &["leaderboard", "1234", "--year", "2022"]);
+    let requests = server.requests_to("/2022/leaderboard/private/view/1234.json");
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].headers["cookie"], "session=test-session");
+}
+
+#[test]
+fn leaderboard_fails_on_a_page_that_is_not_the_leaderboard_and_does_not_keep_it() {
+    let server = Server::start();
+    server.route("GET", "/2022/leaderboard/private/view/1234.json", 200, &page("home_logged_in.html"));
+    let dir = workdir();
+    let output = aoc(&dir, &server, &["leaderboard", "1234", "--year", "2022"]);
+    assert_eq!(output.status.code(), Some(12));
+    assert!(stderr(&output).contains("Could not read leaderboard 1234 for 2022"), "{}", stderr(&output));
+
+    aoc(&dir, &server, &["leaderboard", "1234", "--year", "2022"]);
+    assert_eq!(server.requests_to("/2022/leaderboard/private/view/1234.json").len(), 2);
+}
+
+#[test]
+fn calendar_flags_days_that_disagree_with_the_solutions() {
+    let server = Server::start();
+    server.route("GET", "/2022", 200, &page("calendar_2022.html"));
+    let dir = workdir();
+    let output = aoc(&dir, &server, &["calendar", "2022"]);
+    assert!(output.status.success(), "{}", stderr(&output));
+    let text = stdout(&output);
+    assert!(text.lines().nth(1).unwrap().starts_with("stars   *  *  .  ."));
+    assert!(text.contains("Total: 5 stars"));
+    assert!(text.contains("2022 day 2: 2 stars on the site but only part 1 is solved"));
+    assert!(text.contains("2022 day 3: solution but no stars on the site"));
+    assert!(text.contains("2022 day 22: 1 star on the site but no solution"));
+    assert!(!text.contains("2022 day 1:"));
+}
+
+#[test]
+fn submit_posts_the_answer_and_reports_the_verdict() {
+    let server = Server::start();
+    server.route("POST", "/2022/day/1/answer", 200, &page("answer_correct.html"));
+    let dir = workdir();
+    let output = aoc(&dir, &server, &["submit", "2022", "1", "1", "67450"]);
+    assert!(stdout(&output).contains("Correct!"), "{}", stderr(&output));
+    let request = &server.requests_to("/2022/day/1/answer")[0];
+    assert_eq!(request.body, "level=1&answer=67450");
+    assert_eq!(request.headers["cookie"], "session=test-session");
+    let answers = fs::read_to_string(dir.join("answers.toml")).unwrap();
+    assert_eq!(answers, "[[default]]\nyear = 2022\nday = 1\npart1 = \"67450\"\n");
+
+    let output = aoc(&dir, &server, &["submit", "2022", "1", "3", "1"]);
+    assert_eq!(output.status.code(), Some(2));
+    assert_eq!(server.requests_to("/2022/day/1/answer").len(), 1);
+}
+
+#[test]
+fn verify_checks_solutions_against_the_answers_on_the_puzzle_page() {
+    let server = day1_server();
+    let dir = workdir();
+    let output = aoc(&dir, &server, &["pull", "2022", "1"]);
+    assert!(stdout(&output).contains("Recorded the answers for 2022 day 1"), "{}", stderr(&output));
+    let answers = fs::read_to_string(dir.join("answers.toml")).unwrap();
+    assert!(answers.contains("part1 = \"67450\"\npart2 = \"199357\""));
+
+    //the served input is the example, so the recorded answers no longer match
+    let output = aoc(&dir, &server, &["verify"]);
+    assert_eq!(output.status.code(), Some(15));
+    let text = stdout(&output);
+    assert!(text.contains("2022  1    1     67450     24000  MISMATCH"), "{}", text);
+    assert!(text.contains("2 of 2 answers differ"));
+
+    fs::write(dir.join("answers.toml"), "[[default]]\nyear = 2022\nday = 1\npart1 = \"24000\"\npart2 = \"45000\"\n").unwrap();
+    let output = aoc(&dir, &server, &["verify", "2022", "1"]);
+    assert!(stdout(&output).contains("0 of 2 answers differ"), "{}", stderr(&output));
+    let output = aoc(&dir, &server, &["verify", "2015"]);
+    assert!(stderr(&output).contains("No known answers"));
+
+    fs::write(dir.join("answers.toml"), "[[default]\n").unwrap();
+    let output = aoc(&dir, &server, &["verify"]);
+    assert!(stderr(&output).contains("answers.toml"), "{}", stderr(&output));
+    assert_eq!(output.status.code(), Some(8));
+}
+
+#[test]
+fn submit_keeps_answers_it_cannot_read() {
+    let server = Server::start();
+    server.route("POST", "/2022/day/1/answer", 200, &page("answer_correct.html"));
+    let dir = workdir();
+    fs::write(dir.join("answers.toml"), "[[default]\n").unwrap();
+    let output = aoc(&dir, &server, &["submit", "2022", "1", "1", "67450"]);
+    assert!(stdout(&output).contains("Correct!"), "{}", stderr(&output));
+    assert!(stderr(&output).contains("Could not read"), "{}", stderr(&output));
+    assert_eq!(output.status.code(), Some(8));
+    assert_eq!(fs::read_to_string(dir.join("answers.toml")).unwrap(), "[[default]\n");
+    assert!(fs::read_to_string(dir.join(".aoc/ledger.toml")).unwrap().contains("verdict = \"correct\""));
+}
+
+#[test]
+fn submit_reports_wrong_and_rate_limited_answers() {
+    let server = Server::start();
+    server
+        .route("POST", "/2022/day/1/answer", 200, &page("answer_too_high.html"))
+        .route("POST", "/2022/day/3/answer", 200, &page("answer_rate_limited.html"));
+    let dir = workdir();
+    let output = aoc(&dir, &server, &["submit", "2022", "1", "2", "999999"]);
+    assert!(stderr(&output).contains("too high"));
+    assert_eq!(output.status.code(), Some(18));
+    let output = aoc(&dir, &server, &["submit", "2022", "3", "1", "123"]);
+    assert!(stderr(&output).contains("wait 42s"));
+    assert_eq!(output.status.code(), Some(18));
+}
+
+#[test]
+fn submit_rejects_answers_the_ledger_knows_are_wrong() {
+    let server = Server::start();
+    server.route("POST", "/2022/day/1/answer", 200, &page("answer_too_high.html"));
+    let dir = workdir();
+    aoc(&dir, &server, &["submit", "2022", "1", "2", "999999"]);
+    let ledger = fs::read_to_string(dir.join(".aoc/ledger.toml")).unwrap();
+    assert!(ledger.contains("answer = \"999999\""));
+    assert!(ledger.contains("verdict = \"too high\""));
+
+    let output = aoc(&dir, &server, &["submit", "2022", "1", "2", "999999"]);
+    assert!(stderr(&output).contains("999999 was already submitted"));
+    assert_eq!(output.status.code(), Some(17));
+    let output = aoc(&dir, &server, &["submit", "2022", "1", "2", "1000000"]);
+    assert!(stderr(&output).contains("not below 999999"));
+    assert_eq!(output.status.code(), Some(17));
+    assert_eq!(server.requests_to("/2022/day/1/answer").len(), 1);
+
+    //bounds belong to the part and the account they were found for
+    aoc(&dir, &server, &["submit", "2022", "1", "1", "1000000"]);
+    assert_eq!(server.requests_to("/2022/day/1/answer").len(), 2);
+}
+
+#[test]
+fn submit_keeps_one_ledger_for_the_whole_project() {
+    let server = Server::start();
+    server.route("POST", "/2022/day/1/answer", 200, &page("answer_too_high.html"));
+    let dir = workdir();
+    fs::write(dir.join("aoc.toml"), "").unwrap();
+    fs::create_dir_all(dir.join("src")).unwrap();
+    aoc(&dir.join("src"), &server, &["submit", "2022", "1", "2", "999999"]);
+    assert!(dir.join(".aoc/ledger.toml").exists());
+    assert!(!dir.join("src/.aoc").exists());
+
+    let output = aoc(&dir, &server, &["submit", "2022", "1", "2", "999999"]);
+    assert!(stderr(&output).contains("999999 was already submitted"));
+
+    fs::write(dir.join(".aoc/ledger.toml"), "[[submissions]\n").unwrap();
+    let output = aoc(&dir, &server, &["submit", "2022", "1", "2", "1"]);
+    assert_eq!(output.status.code(), Some(8));
+    assert!(stderr(&output).contains("ledger.toml"), "{}", stderr(&output));
+    assert_eq!(server.requests_to("/2022/day/1/answer").len(), 1);
+}
+
+#[test]
+fn submit_without_answer_runs_the_solution() {
+    let server = Server::start();
+    server.route("POST", "/2022/day/1/answer", 200, &page("answer_correct.html"));
+    let dir = workdir();
+    fs::create_dir_all(dir.join("inputs")).unwrap();
+    fs::write(dir.join("inputs/2022_1.txt"), page("input_2022_1.txt")).unwrap();
+    let output = aoc(&dir, &server, &["submit", "2022", "1", "2"]);
+    assert!(stdout(&output).contains("Submitting 45000"), "{}", stderr(&output));
+    assert_eq!(server.requests_to("/2022/day/1/answer")[0].body, "level=2&answer=45000");
+}
+
+#[test]
+fn submit_reports_server_errors() {
+    let server = Server::start();
+    server.route("POST", "/2022/day/1/answer", 500, &page("server_error.html"));
+    let dir = workdir();
+    let output = aoc(&dir, &server, &["submit", "2022", "1", "1", "1"]);
+    assert_eq!(output.status.code(), Some(6));
+    assert!(stderr(&output).contains("error (500)"));
+}